@@ -0,0 +1,113 @@
+//! Batch APIs against the naive per-element loops they replace.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use maths::{Aabb, Matrix4, Quaternion, Vector3};
+use std::hint::black_box;
+
+const SIZES: [usize; 3] = [64, 1024, 16384];
+
+fn points(count: usize) -> Vec<Vector3> {
+    (0..count)
+        .map(|i| {
+            let i = i as f32;
+            Vector3::new((i * 0.37).sin() * 50.0, (i * 0.11).cos() * 20.0, i * 0.01)
+        })
+        .collect()
+}
+
+fn transform_points(c: &mut Criterion) {
+    let m = Matrix4::from_rotation_translation_scale(
+        Quaternion::from_euler_degrees(Vector3::new(20.0, -35.0, 80.0)),
+        Vector3::new(1.0, -2.0, 3.0),
+        Vector3::new(2.0, 1.0, 0.5),
+    );
+    let mut group = c.benchmark_group("transform_points");
+    for size in SIZES {
+        let input = points(size);
+        let mut out = vec![Vector3::ZERO; size];
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("naive", size), &input, |b, input| {
+            b.iter(|| {
+                let m = black_box(&m);
+                for (point, out) in input.iter().zip(out.iter_mut()) {
+                    *out = m.transform_point(*point);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), &input, |b, input| {
+            b.iter(|| black_box(&m).transform_points(input, &mut out))
+        });
+    }
+    group.finish();
+}
+
+fn rotate(c: &mut Criterion) {
+    let rotation = Quaternion::from_euler_degrees(Vector3::new(20.0, -35.0, 80.0));
+    let mut group = c.benchmark_group("rotate");
+    for size in SIZES {
+        let mut values = points(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_function(BenchmarkId::new("naive", size), |b| {
+            b.iter(|| {
+                let rotation = black_box(&rotation);
+                for value in values.iter_mut() {
+                    *value = rotation.rotate(*value);
+                }
+            })
+        });
+        group.bench_function(BenchmarkId::new("batch", size), |b| {
+            b.iter(|| black_box(&rotation).rotate_slice(&mut values))
+        });
+    }
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize");
+    for size in SIZES {
+        let input = points(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_function(BenchmarkId::new("naive", size), |b| {
+            let mut values = input.clone();
+            b.iter(|| {
+                values.copy_from_slice(&input);
+                for value in values.iter_mut() {
+                    value.normalize();
+                }
+            })
+        });
+        group.bench_function(BenchmarkId::new("batch", size), |b| {
+            let mut values = input.clone();
+            b.iter(|| {
+                values.copy_from_slice(&input);
+                Vector3::normalize_slice(&mut values);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bounds(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bounds");
+    for size in SIZES {
+        let input = points(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("naive", size), &input, |b, input| {
+            b.iter(|| {
+                let mut bounds = Aabb::new(input[0], input[0]);
+                for point in black_box(input) {
+                    bounds.min = bounds.min.min(*point);
+                    bounds.max = bounds.max.max(*point);
+                }
+                bounds
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), &input, |b, input| {
+            b.iter(|| Aabb::from_points(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, transform_points, rotate, normalize, bounds);
+criterion_main!(benches);
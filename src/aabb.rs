@@ -0,0 +1,102 @@
+use crate::batch;
+use crate::Vector3;
+
+/// An axis aligned bounding box, `min` and `max` are inclusive corners.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Aabb {
+	pub min: Vector3,
+	pub max: Vector3,
+}
+
+impl Aabb {
+	pub const fn new(min: Vector3, max: Vector3) -> Self {
+		Aabb { min, max }
+	}
+
+	/// Smallest box holding every point, `None` when `points` is empty.
+	pub fn from_points(points: &[Vector3]) -> Option<Self> {
+		batch::bounds(points)
+	}
+
+	pub const fn center(&self) -> Vector3 {
+		self.min.add(self.max).scale(0.5)
+	}
+
+	pub const fn size(&self) -> Vector3 {
+		self.max.sub(self.min)
+	}
+
+	pub const fn half_extents(&self) -> Vector3 {
+		self.size().scale(0.5)
+	}
+
+	pub const fn contains_point(&self, point: Vector3) -> bool {
+		point.x >= self.min.x && point.x <= self.max.x &&
+		point.y >= self.min.y && point.y <= self.max.y &&
+		point.z >= self.min.z && point.z <= self.max.z
+	}
+
+	/// True when the boxes overlap or touch.
+	pub const fn intersects(&self, other: &Aabb) -> bool {
+		self.min.x <= other.max.x && self.max.x >= other.min.x &&
+		self.min.y <= other.max.y && self.max.y >= other.min.y &&
+		self.min.z <= other.max.z && self.max.z >= other.min.z
+	}
+
+	/// Smallest box holding both boxes.
+	pub fn union(&self, other: Aabb) -> Aabb {
+		Aabb { min: self.min.min(other.min), max: self.max.max(other.max) }
+	}
+
+	/// The point of the box closest to `point`, which is `point` itself when inside.
+	pub fn closest_point(&self, point: Vector3) -> Vector3 {
+		point.clamp(self.min, self.max)
+	}
+
+	/// Zero when `point` is inside.
+	pub fn distance_squared(&self, point: Vector3) -> f32 {
+		let offset = point - self.closest_point(point);
+		offset.dot(offset)
+	}
+
+	pub fn distance(&self, point: Vector3) -> f32 {
+		libm::sqrtf(self.distance_squared(point))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Aabb, Vector3};
+
+	#[test]
+	fn from_points() {
+		assert_eq!(Aabb::from_points(&[]), None);
+
+		let points = [
+			Vector3::new(1.0, 2.0, 3.0),
+			Vector3::new(-4.0, 0.5, 9.0),
+			Vector3::new(2.0, -1.0, 0.0),
+			Vector3::new(0.0, 0.0, 0.0),
+			Vector3::new(0.5, 7.0, -2.0),
+			Vector3::new(3.0, 1.0, 1.0),
+		];
+		let bounds = Aabb::from_points(&points).unwrap();
+		assert_eq!(bounds, Aabb::new(Vector3::new(-4.0, -1.0, -2.0), Vector3::new(3.0, 7.0, 9.0)));
+		assert!(points.iter().all(|point| bounds.contains_point(*point)));
+		assert_eq!(bounds.center(), Vector3::new(-0.5, 3.0, 3.5));
+		assert!(bounds.intersects(&Aabb::new(Vector3::splat(3.0), Vector3::splat(4.0))));
+		assert!(!bounds.intersects(&Aabb::new(Vector3::splat(9.5), Vector3::splat(10.0))));
+		assert_eq!(bounds.union(Aabb::new(Vector3::ZERO, Vector3::splat(10.0))).max, Vector3::splat(10.0));
+	}
+
+	#[test]
+	fn closest_point() {
+		let bounds = Aabb::new(Vector3::ZERO, Vector3::new(2.0, 4.0, 6.0));
+		assert_eq!(bounds.closest_point(Vector3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, 2.0, 3.0));
+		assert_eq!(bounds.closest_point(Vector3::new(5.0, -1.0, 3.0)), Vector3::new(2.0, 0.0, 3.0));
+		assert_eq!(bounds.distance_squared(Vector3::new(5.0, -1.0, 3.0)), 10.0);
+		assert_eq!(bounds.distance(Vector3::new(1.0, 7.0, 10.0)), 5.0);
+	}
+}
@@ -0,0 +1,219 @@
+use core::f32::consts::{PI, TAU};
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use libm::{cosf, fmodf, sinf, tanf};
+
+macro_rules! impl_angle {
+	($ty:ident, $full_turn:expr, $suffix:literal) => {
+		impl $ty {
+			pub const ZERO: $ty = $ty(0.0);
+			pub const QUARTER_TURN: $ty = $ty($full_turn / 4.0);
+			pub const HALF_TURN: $ty = $ty($full_turn / 2.0);
+			pub const FULL_TURN: $ty = $ty($full_turn);
+
+			/// Wraps into `[0, FULL_TURN)`.
+			pub fn wrap_positive(self) -> $ty {
+				let wrapped = fmodf(self.0, $full_turn);
+				let wrapped = if wrapped < 0.0 { wrapped + $full_turn } else { wrapped };
+				// A tiny negative remainder can round up to a full turn
+				$ty(if wrapped >= $full_turn { 0.0 } else { wrapped })
+			}
+
+			/// Wraps into `(-HALF_TURN, HALF_TURN]`.
+			pub fn wrap_signed(self) -> $ty {
+				let wrapped = self.wrap_positive().0;
+				$ty(if wrapped > $full_turn / 2.0 { wrapped - $full_turn } else { wrapped })
+			}
+
+			/// The signed shortest rotation from `self` to `other`, in `(-HALF_TURN, HALF_TURN]`.
+			pub fn shortest_difference(self, other: $ty) -> $ty {
+				(other - self).wrap_signed()
+			}
+
+			pub fn sin(self) -> f32 {
+				sinf(Radians::from(self).0)
+			}
+
+			pub fn cos(self) -> f32 {
+				cosf(Radians::from(self).0)
+			}
+
+			pub fn tan(self) -> f32 {
+				tanf(Radians::from(self).0)
+			}
+
+			pub fn sin_cos(self) -> (f32, f32) {
+				let radians = Radians::from(self).0;
+				(sinf(radians), cosf(radians))
+			}
+		}
+
+		impl fmt::Display for $ty {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				fmt::Display::fmt(&self.0, f)?;
+				f.write_str($suffix)
+			}
+		}
+
+		impl Neg for $ty {
+			type Output = $ty;
+
+			fn neg(self) -> Self::Output {
+				$ty(-self.0)
+			}
+		}
+
+		impl Add for $ty {
+			type Output = $ty;
+
+			fn add(self, rhs: $ty) -> Self::Output {
+				$ty(self.0 + rhs.0)
+			}
+		}
+		impl AddAssign for $ty {
+			fn add_assign(&mut self, rhs: $ty) {
+				self.0 += rhs.0;
+			}
+		}
+
+		impl Sub for $ty {
+			type Output = $ty;
+
+			fn sub(self, rhs: $ty) -> Self::Output {
+				$ty(self.0 - rhs.0)
+			}
+		}
+		impl SubAssign for $ty {
+			fn sub_assign(&mut self, rhs: $ty) {
+				self.0 -= rhs.0;
+			}
+		}
+
+		impl Mul<f32> for $ty {
+			type Output = $ty;
+
+			fn mul(self, rhs: f32) -> Self::Output {
+				$ty(self.0 * rhs)
+			}
+		}
+		impl MulAssign<f32> for $ty {
+			fn mul_assign(&mut self, rhs: f32) {
+				self.0 *= rhs;
+			}
+		}
+
+		impl Div<f32> for $ty {
+			type Output = $ty;
+
+			fn div(self, rhs: f32) -> Self::Output {
+				$ty(self.0 / rhs)
+			}
+		}
+		impl DivAssign<f32> for $ty {
+			fn div_assign(&mut self, rhs: f32) {
+				self.0 /= rhs;
+			}
+		}
+
+		/// The ratio of two angles.
+		impl Div for $ty {
+			type Output = f32;
+
+			fn div(self, rhs: $ty) -> Self::Output {
+				self.0 / rhs.0
+			}
+		}
+	};
+}
+
+/// An angle in radians.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Radians(pub f32);
+
+/// An angle in degrees.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Degrees(pub f32);
+
+impl Radians {
+	pub const fn to_degrees(self) -> Degrees {
+		Degrees(self.0 * (180.0 / PI))
+	}
+}
+
+impl Degrees {
+	pub const fn to_radians(self) -> Radians {
+		Radians(self.0 * (PI / 180.0))
+	}
+}
+
+impl From<Degrees> for Radians {
+	fn from(value: Degrees) -> Self {
+		value.to_radians()
+	}
+}
+
+impl From<Radians> for Degrees {
+	fn from(value: Radians) -> Self {
+		value.to_degrees()
+	}
+}
+
+impl_angle!(Radians, TAU, " rad");
+impl_angle!(Degrees, 360.0, "°");
+
+#[cfg(test)]
+mod tests {
+	use crate::{Degrees, Matrix4, Quaternion, Radians, Vector3};
+	use core::f32::consts::{FRAC_PI_2, PI};
+
+	#[test]
+	fn wrapping() {
+		assert_eq!(Degrees(-90.0).wrap_positive(), Degrees(270.0));
+		assert_eq!(Degrees(720.0).wrap_positive(), Degrees(0.0));
+		assert_eq!(Degrees(-1e-6).wrap_positive(), Degrees(0.0));
+		assert_eq!(Degrees(180.0).wrap_signed(), Degrees(180.0));
+		assert_eq!(Degrees(-180.0).wrap_signed(), Degrees(180.0));
+		assert_eq!(Degrees(190.0).wrap_signed(), Degrees(-170.0));
+		assert_eq!(Radians(-PI).wrap_signed(), Radians(PI));
+
+		assert_eq!(Degrees(350.0).shortest_difference(Degrees(10.0)), Degrees(20.0));
+		assert_eq!(Degrees(10.0).shortest_difference(Degrees(350.0)), Degrees(-20.0));
+		assert!((Radians(3.0).shortest_difference(Radians(-3.0)).0 - (2.0 * PI - 6.0)).abs() < 1e-6);
+	}
+
+	#[test]
+	fn conversion() {
+		assert_eq!(Radians::from(Degrees(90.0)), Radians(FRAC_PI_2));
+		assert_eq!(Degrees::from(Radians::HALF_TURN), Degrees::HALF_TURN);
+		assert_eq!(Degrees(30.0) * 3.0 - Degrees(45.0), Degrees(45.0));
+		assert_eq!(Degrees::FULL_TURN / Degrees::QUARTER_TURN, 4.0);
+		assert!((Degrees(30.0).sin() - 0.5).abs() < 1e-6 && (Radians::ZERO.cos() - 1.0).abs() < 1e-6);
+	}
+
+	#[test]
+	fn constructors() {
+		let degrees = Quaternion::from_axis_angle(Vector3::UP, Degrees(90.0));
+		let radians = Quaternion::from_axis_angle(Vector3::UP, Radians(FRAC_PI_2));
+		assert!((degrees.y - radians.y).abs() < 1e-6 && (degrees.w - radians.w).abs() < 1e-6);
+		assert_eq!(Quaternion::from_axis_angle_degrees(Vector3::UP, 90.0), degrees);
+
+		// Each unit is converted at most once
+		assert_eq!(radians.y, libm::sinf(FRAC_PI_2 * 0.5));
+		let turn = Quaternion::from_axis_angle(Vector3::UP, Degrees(73.0));
+		assert_eq!((turn.y, turn.w), (libm::sinf(73.0 * (PI / 360.0)), libm::cosf(73.0 * (PI / 360.0))));
+
+		let euler = Quaternion::from_euler([Degrees(30.0), Degrees(45.0), Degrees(-60.0)]);
+		let radians = Quaternion::from_euler([Degrees(30.0), Degrees(45.0), Degrees(-60.0)].map(Degrees::to_radians));
+		assert!((euler.x - radians.x).abs() < 1e-6 && (euler.w - radians.w).abs() < 1e-6);
+		assert_eq!(Quaternion::from_euler_degrees(Vector3::new(30.0, 45.0, -60.0)), euler);
+
+		let perspective = Matrix4::from_perspective_radians(1.2, 1.5, 0.1, 100.0);
+		assert_eq!(Matrix4::from_perspective(Radians(1.2), 1.5, 0.1, 100.0), perspective);
+		let fov = Matrix4::from_perspective(Degrees(60.0), 1.5, 0.1, 100.0);
+		assert!((fov.m11 - 1.0 / libm::tanf(PI / 6.0)).abs() < 1e-5);
+	}
+}
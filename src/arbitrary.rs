@@ -0,0 +1,165 @@
+//! [`Arbitrary`] support for fuzzing, enabled by the `arbitrary` feature.
+//!
+//! The types themselves take any bit pattern, NaN and infinity included. Wrap them in
+//! [`Finite`], [`Unit`], [`Affine`] or [`Invertible`] to only receive values that satisfy
+//! those invariants.
+
+use crate::generate;
+use crate::{IVector2, LinearRgba, Matrix3, Matrix4, Quaternion, Srgba, Vector2, Vector3, Vector4};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Bound on the components of [`Finite`] vectors and the translation of [`Affine`] matrices.
+pub const FINITE_BOUND: f32 = 1.0e4;
+
+macro_rules! impl_arbitrary {
+	($ty:ident, $scalar:ty, [$($field:ident),+], $build:expr) => {
+		impl<'a> Arbitrary<'a> for $ty {
+			fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+				$(let $field: $scalar = u.arbitrary()?;)+
+				Ok($build)
+			}
+		}
+	};
+}
+
+impl_arbitrary!(Vector2, f32, [x, y], Vector2 { x, y });
+impl_arbitrary!(Vector3, f32, [x, y, z], Vector3::new(x, y, z));
+impl_arbitrary!(Vector4, f32, [x, y, z, w], Vector4 { x, y, z, w });
+impl_arbitrary!(IVector2, i32, [x, y], IVector2 { x, y });
+impl_arbitrary!(Quaternion, f32, [x, y, z, w], Quaternion { x, y, z, w });
+impl_arbitrary!(LinearRgba, f32, [r, g, b, a], LinearRgba { r, g, b, a });
+impl_arbitrary!(Srgba, f32, [r, g, b, a], Srgba { r, g, b, a });
+impl_arbitrary!(Matrix3, Vector3, [x, y, z], Matrix3 { x, y, z });
+impl_arbitrary!(Matrix4, f32, [
+	m00, m01, m02, m03,
+	m10, m11, m12, m13,
+	m20, m21, m22, m23,
+	m30, m31, m32, m33
+], Matrix4 {
+	m00, m01, m02, m03,
+	m10, m11, m12, m13,
+	m20, m21, m22, m23,
+	m30, m31, m32, m33,
+});
+
+/// Uniform float in `[0, 1)`.
+fn unit_interval(u: &mut Unstructured) -> Result<f32> {
+	Ok((u.arbitrary::<u32>()? >> 8) as f32 / (1 << 24) as f32)
+}
+
+/// Uniform float in `[-bound, bound)`.
+fn bounded(u: &mut Unstructured, bound: f32) -> Result<f32> {
+	Ok((unit_interval(u)? * 2.0 - 1.0) * bound)
+}
+
+/// A vector with every component in `[-FINITE_BOUND, FINITE_BOUND)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Finite<T>(pub T);
+
+impl<'a> Arbitrary<'a> for Finite<Vector2> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		Ok(Finite(Vector2 { x: bounded(u, FINITE_BOUND)?, y: bounded(u, FINITE_BOUND)? }))
+	}
+}
+
+impl<'a> Arbitrary<'a> for Finite<Vector3> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		Ok(Finite(Vector3::new(bounded(u, FINITE_BOUND)?, bounded(u, FINITE_BOUND)?, bounded(u, FINITE_BOUND)?)))
+	}
+}
+
+impl<'a> Arbitrary<'a> for Finite<Vector4> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		Ok(Finite(Vector4 {
+			x: bounded(u, FINITE_BOUND)?,
+			y: bounded(u, FINITE_BOUND)?,
+			z: bounded(u, FINITE_BOUND)?,
+			w: bounded(u, FINITE_BOUND)?,
+		}))
+	}
+}
+
+/// A unit length vector or normalized quaternion, uniformly distributed over directions or rotations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Unit<T>(pub T);
+
+impl<'a> Arbitrary<'a> for Unit<Vector3> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		Ok(Unit(generate::unit_vector3(bounded(u, 1.0)?, unit_interval(u)?)))
+	}
+}
+
+impl<'a> Arbitrary<'a> for Unit<Quaternion> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		Ok(Unit(generate::unit_quaternion(unit_interval(u)?, unit_interval(u)?, unit_interval(u)?)))
+	}
+}
+
+/// A [`Matrix4`] built by [`Matrix4::from_rotation_translation_scale`] from a normalized rotation,
+/// a translation within [`FINITE_BOUND`] and a scale between 0.1 and 10 on each axis, possibly mirrored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine<T>(pub T);
+
+impl<'a> Arbitrary<'a> for Affine<Matrix4> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let Unit(rotation) = u.arbitrary()?;
+		let Finite(translation) = u.arbitrary()?;
+		let scale = Vector3::new(
+			generate::scale_factor(unit_interval(u)?, u.arbitrary()?),
+			generate::scale_factor(unit_interval(u)?, u.arbitrary()?),
+			generate::scale_factor(unit_interval(u)?, u.arbitrary()?),
+		);
+		Ok(Affine(Matrix4::from_rotation_translation_scale(rotation, translation, scale)))
+	}
+}
+
+/// A well conditioned, and so safely invertible, general [`Matrix4`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Invertible<T>(pub T);
+
+impl<'a> Arbitrary<'a> for Invertible<Matrix4> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let mut values = [0.0; 16];
+		for value in &mut values {
+			*value = bounded(u, 1.0)?;
+		}
+		Ok(Invertible(generate::invertible_matrix4(values)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::arbitrary::{Affine, Finite, Invertible, Unit, FINITE_BOUND};
+	use crate::{Matrix4, Quaternion, Vector3};
+	use arbitrary::Unstructured;
+
+	#[test]
+	fn invariants() {
+		let mut bytes = [0u8; 4096];
+		let mut state = 0x2545_f491_u32;
+		for byte in &mut bytes {
+			state ^= state << 13;
+			state ^= state >> 17;
+			state ^= state << 5;
+			*byte = state as u8;
+		}
+
+		let mut u = Unstructured::new(&bytes);
+		for _ in 0..20 {
+			let Finite(v) = u.arbitrary::<Finite<Vector3>>().unwrap();
+			assert!(v.x.abs() <= FINITE_BOUND && v.y.abs() <= FINITE_BOUND && v.z.abs() <= FINITE_BOUND);
+
+			let Unit(direction) = u.arbitrary::<Unit<Vector3>>().unwrap();
+			assert!((direction.magnitude() - 1.0).abs() < 1e-5);
+
+			let Unit(rotation) = u.arbitrary::<Unit<Quaternion>>().unwrap();
+			assert!((rotation.magnitude() - 1.0).abs() < 1e-5);
+
+			let Affine(m) = u.arbitrary::<Affine<Matrix4>>().unwrap();
+			assert!(m.m03 == 0.0 && m.m13 == 0.0 && m.m23 == 0.0 && m.m33 == 1.0);
+
+			let Invertible(m) = u.arbitrary::<Invertible<Matrix4>>().unwrap();
+			assert!(m.m00.abs() >= 4.0 && m.m33.abs() >= 4.0);
+		}
+	}
+}
@@ -0,0 +1,502 @@
+use crate::collision::ray::slab;
+use crate::collision::{Ray, Sphere};
+use crate::{Aabb, Vector3};
+use alloc::vec::Vec;
+
+/// Nodes deeper than this are split at the median, which bounds the depth and so the traversal stack.
+const SAH_DEPTH: usize = 32;
+const MAX_DEPTH: usize = SAH_DEPTH + 32;
+const BINS: usize = 16;
+/// Leaves above this size are always split.
+const MAX_LEAF_SIZE: usize = 8;
+
+/// A node of [`Bvh::nodes`], laid out as `{ vec3 min; uint offset; vec3 max; uint count; }` with
+/// a 32 byte stride under both std140 and std430.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct BvhNode {
+	pub min: [f32; 3],
+	/// For leaves the first entry of [`Bvh::indices`] they hold, for interior nodes the index of
+	/// their first child, the second child follows it.
+	pub offset: u32,
+	pub max: [f32; 3],
+	/// How many primitives a leaf holds, zero for interior nodes.
+	pub count: u32,
+}
+
+impl BvhNode {
+	pub const fn is_leaf(&self) -> bool {
+		self.count > 0
+	}
+
+	pub const fn bounds(&self) -> Aabb {
+		let (min, max) = (self.min, self.max);
+		Aabb::new(Vector3::new(min[0], min[1], min[2]), Vector3::new(max[0], max[1], max[2]))
+	}
+
+	fn set_bounds(&mut self, bounds: Aabb) {
+		self.min = [bounds.min.x, bounds.min.y, bounds.min.z];
+		self.max = [bounds.max.x, bounds.max.y, bounds.max.z];
+	}
+}
+
+/// A bounding volume hierarchy over boxes, such as the bounds of a level's triangles.
+///
+/// The tree only knows each primitive's box, queries hand candidate primitive indices to a
+/// callback that does the exact test. The root is node 0 and children always come after their
+/// parent, so [`Bvh::nodes`] and [`Bvh::indices`] can be uploaded as they are and traversed on
+/// the GPU with a stack of 64 entries.
+///
+/// ```
+/// use maths::collision::{Bvh, Culling, Ray, Triangle};
+/// use maths::{Aabb, Vector3};
+///
+/// let triangles: Vec<Triangle> = (0..100)
+///     .map(|i| {
+///         let corner = Vector3::new(i as f32, 0.0, 0.0);
+///         Triangle::new(corner, corner + Vector3::new(0.0, 0.0, 1.0), corner + Vector3::RIGHT)
+///     })
+///     .collect();
+/// let bounds: Vec<Aabb> = triangles.iter().map(|t| Aabb::from_points(&[t.a, t.b, t.c]).unwrap()).collect();
+/// let bvh = Bvh::build(&bounds);
+///
+/// let ray = Ray::new(Vector3::new(41.2, 5.0, 0.3), Vector3::DOWN);
+/// let hit = bvh.cast_ray(&ray, f32::INFINITY, |i| {
+///     ray.intersect_triangle(&triangles[i], Culling::Back).map(|hit| hit.distance)
+/// });
+/// assert_eq!(hit, Some((41, 5.0)));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bvh {
+	nodes: Vec<BvhNode>,
+	indices: Vec<u32>,
+}
+
+impl Bvh {
+	/// Builds the tree with the surface area heuristic, binned along each axis.
+	pub fn build(bounds: &[Aabb]) -> Bvh {
+		let mut bvh = Bvh { nodes: Vec::new(), indices: (0..bounds.len() as u32).collect() };
+		if bounds.is_empty() {
+			return bvh;
+		}
+		let centers: Vec<Vector3> = bounds.iter().map(Aabb::center).collect();
+		let leaf = BvhNode { min: [0.0; 3], offset: 0, max: [0.0; 3], count: 0 };
+		bvh.nodes.push(leaf);
+
+		let mut pending = Vec::from([(0, 0, bounds.len(), 0)]);
+		while let Some((node, start, end, depth)) = pending.pop() {
+			let indices = &mut bvh.indices[start..end];
+			let node_bounds = union(bounds, indices);
+			bvh.nodes[node].set_bounds(node_bounds);
+
+			let split = if indices.len() == 1 {
+				None
+			} else if depth < SAH_DEPTH {
+				sah_split(bounds, &centers, indices, half_area(&node_bounds))
+			} else {
+				Some(median_split(&centers, indices))
+			};
+			let split = match split {
+				Some(split) => split,
+				None if indices.len() > MAX_LEAF_SIZE => median_split(&centers, indices),
+				None => {
+					bvh.nodes[node].offset = start as u32;
+					bvh.nodes[node].count = indices.len() as u32;
+					continue;
+				}
+			};
+
+			let first = bvh.nodes.len();
+			bvh.nodes[node].offset = first as u32;
+			bvh.nodes.extend([leaf, leaf]);
+			pending.push((first, start, start + split, depth + 1));
+			pending.push((first + 1, start + split, end, depth + 1));
+		}
+		bvh
+	}
+
+	/// Updates every node's bounds after primitives moved, keeping the tree's structure. Cheaper
+	/// than a rebuild, but queries slow down as the primitives drift from where they were built.
+	/// Panics if `bounds` does not have one box per primitive.
+	pub fn refit(&mut self, bounds: &[Aabb]) {
+		assert_eq!(bounds.len(), self.indices.len(), "refit needs one box per primitive");
+		// Children come after their parent, so walking backwards sees them first
+		for node in (0..self.nodes.len()).rev() {
+			let BvhNode { offset, count, .. } = self.nodes[node];
+			let (offset, count) = (offset as usize, count as usize);
+			let node_bounds = if count > 0 {
+				union(bounds, &self.indices[offset..offset + count])
+			} else {
+				self.nodes[offset].bounds().union(self.nodes[offset + 1].bounds())
+			};
+			self.nodes[node].set_bounds(node_bounds);
+		}
+	}
+
+	/// The flattened tree, the root first.
+	pub fn nodes(&self) -> &[BvhNode] {
+		&self.nodes
+	}
+
+	/// Primitive indices, in the order leaves refer to them.
+	pub fn indices(&self) -> &[u32] {
+		&self.indices
+	}
+
+	/// Bounds of every primitive, `None` when there are none.
+	pub fn bounds(&self) -> Option<Aabb> {
+		self.nodes.first().map(BvhNode::bounds)
+	}
+
+	/// Closest primitive along `ray` within `max_distance`, with its distance. `hit` tests one
+	/// primitive and returns where the ray hits it, in the same units as [`Ray::at`]. Nearer
+	/// nodes are visited first and nodes beyond the closest hit so far are skipped.
+	pub fn cast_ray(&self, ray: &Ray, max_distance: f32, mut hit: impl FnMut(usize) -> Option<f32>) -> Option<(usize, f32)> {
+		let mut closest = None;
+		let mut max_distance = max_distance;
+		self.cast(ray, &mut max_distance, |index, distance| {
+			closest = Some((index, distance));
+			false
+		}, &mut hit);
+		closest
+	}
+
+	/// Any primitive `ray` hits within `max_distance`, which is cheaper than [`Bvh::cast_ray`]
+	/// when only whether something is in the way matters, such as for shadows and line of sight.
+	pub fn cast_ray_any(&self, ray: &Ray, max_distance: f32, mut hit: impl FnMut(usize) -> Option<f32>) -> Option<(usize, f32)> {
+		let mut any = None;
+		let mut max_distance = max_distance;
+		self.cast(ray, &mut max_distance, |index, distance| {
+			any = Some((index, distance));
+			true
+		}, &mut hit);
+		any
+	}
+
+	/// Calls `overlap` with every primitive whose box overlaps or touches `aabb`.
+	pub fn overlap_aabb(&self, aabb: &Aabb, overlap: impl FnMut(usize)) {
+		self.overlap(|bounds| bounds.intersects(aabb), overlap);
+	}
+
+	/// Calls `overlap` with every primitive whose box overlaps or touches `sphere`.
+	pub fn overlap_sphere(&self, sphere: &Sphere, overlap: impl FnMut(usize)) {
+		let radius_squared = sphere.radius * sphere.radius;
+		self.overlap(|bounds| bounds.distance_squared(sphere.center) <= radius_squared, overlap);
+	}
+
+	fn overlap(&self, test: impl Fn(&Aabb) -> bool, mut overlap: impl FnMut(usize)) {
+		if self.nodes.is_empty() {
+			return;
+		}
+		let mut stack = Stack::new();
+		stack.push(0);
+		while let Some(node) = stack.pop() {
+			let node = self.nodes[node];
+			if !test(&node.bounds()) {
+				continue;
+			}
+			let offset = node.offset as usize;
+			if node.is_leaf() {
+				for index in &self.indices[offset..offset + node.count as usize] {
+					overlap(*index as usize);
+				}
+			} else {
+				stack.push(offset + 1);
+				stack.push(offset);
+			}
+		}
+	}
+
+	/// Walks the nodes `ray` enters before `max_distance`, nearest first. Hits within it are
+	/// reported to `found`, which shrinks `max_distance` to them or stops the walk by returning true.
+	fn cast(&self, ray: &Ray, max_distance: &mut f32, mut found: impl FnMut(usize, f32) -> bool, hit: &mut impl FnMut(usize) -> Option<f32>) {
+		let inverse = Vector3::ONE / ray.direction;
+		let enters = |node: &BvhNode, max_distance: f32| {
+			let bounds = node.bounds();
+			slab(ray.origin, inverse, bounds.min, bounds.max, max_distance)
+		};
+		let Some(root) = self.nodes.first() else { return };
+		let Some(entry) = enters(root, *max_distance) else { return };
+
+		let mut stack = Stack::new();
+		stack.push((0, entry));
+		while let Some((node, entry)) = stack.pop() {
+			if entry > *max_distance {
+				continue;
+			}
+			let node = self.nodes[node];
+			let offset = node.offset as usize;
+			if node.is_leaf() {
+				for index in &self.indices[offset..offset + node.count as usize] {
+					let index = *index as usize;
+					match hit(index) {
+						Some(distance) if distance >= 0.0 && distance <= *max_distance => {
+							*max_distance = distance;
+							if found(index, distance) {
+								return;
+							}
+						}
+						_ => {}
+					}
+				}
+				continue;
+			}
+
+			let first = enters(&self.nodes[offset], *max_distance).map(|entry| (offset, entry));
+			let second = enters(&self.nodes[offset + 1], *max_distance).map(|entry| (offset + 1, entry));
+			match (first, second) {
+				(Some(first), Some(second)) => {
+					let (near, far) = if first.1 <= second.1 { (first, second) } else { (second, first) };
+					stack.push(far);
+					stack.push(near);
+				}
+				(Some(only), None) | (None, Some(only)) => stack.push(only),
+				(None, None) => {}
+			}
+		}
+	}
+}
+
+/// Traversal stack, the depth bound at build time keeps it from overflowing.
+struct Stack<T> {
+	entries: [T; MAX_DEPTH],
+	len: usize,
+}
+
+impl<T: Copy + Default> Stack<T> {
+	fn new() -> Self {
+		Stack { entries: [T::default(); MAX_DEPTH], len: 0 }
+	}
+
+	fn push(&mut self, entry: T) {
+		self.entries[self.len] = entry;
+		self.len += 1;
+	}
+
+	fn pop(&mut self) -> Option<T> {
+		self.len = self.len.checked_sub(1)?;
+		Some(self.entries[self.len])
+	}
+}
+
+fn union(bounds: &[Aabb], indices: &[u32]) -> Aabb {
+	let first = bounds[indices[0] as usize];
+	indices[1..].iter().fold(first, |union, index| union.union(bounds[*index as usize]))
+}
+
+/// Half the surface area, proportional to the chance a random ray hits the box.
+fn half_area(bounds: &Aabb) -> f32 {
+	let size = bounds.size();
+	size.x * size.y + size.y * size.z + size.z * size.x
+}
+
+/// Partitions `indices` at the cheapest bin boundary and returns the size of the first half, or
+/// `None` when keeping them in one leaf is cheaper, counting a traversal step as one intersection.
+fn sah_split(bounds: &[Aabb], centers: &[Vector3], indices: &mut [u32], area: f32) -> Option<usize> {
+	let mut center_bounds = Aabb::new(centers[indices[0] as usize], centers[indices[0] as usize]);
+	for index in indices.iter() {
+		let center = centers[*index as usize];
+		center_bounds = Aabb::new(center_bounds.min.min(center), center_bounds.max.max(center));
+	}
+	let axis_of = |v: Vector3, axis: usize| [v.x, v.y, v.z][axis];
+	let bin_of = |index: u32, axis: usize| {
+		let (min, extent) = (axis_of(center_bounds.min, axis), axis_of(center_bounds.size(), axis));
+		let bin = (axis_of(centers[index as usize], axis) - min) / extent * BINS as f32;
+		(bin as usize).min(BINS - 1)
+	};
+
+	let mut best = None;
+	let mut best_cost = indices.len() as f32;
+	for axis in 0..3 {
+		if axis_of(center_bounds.size(), axis) <= 0.0 {
+			continue;
+		}
+		let mut bins = [(None::<Aabb>, 0); BINS];
+		for index in indices.iter() {
+			let bin = &mut bins[bin_of(*index, axis)];
+			let bounds = bounds[*index as usize];
+			bin.0 = Some(bin.0.map_or(bounds, |union| union.union(bounds)));
+			bin.1 += 1;
+		}
+
+		// Cost of everything left of each boundary, then add the right sides sweeping back
+		let mut costs = [0.0; BINS];
+		let (mut union, mut count) = (None::<Aabb>, 0);
+		for bin in 0..BINS - 1 {
+			union = merge(union, bins[bin].0);
+			count += bins[bin].1;
+			costs[bin] = union.map_or(0.0, |union| half_area(&union)) * count as f32;
+		}
+		let (mut union, mut count) = (None::<Aabb>, 0);
+		for bin in (1..BINS).rev() {
+			union = merge(union, bins[bin].0);
+			count += bins[bin].1;
+			let left = indices.len() - count;
+			let cost = 1.0 + (costs[bin - 1] + union.map_or(0.0, |union| half_area(&union)) * count as f32) / area;
+			if left > 0 && count > 0 && cost < best_cost {
+				best_cost = cost;
+				best = Some((axis, bin));
+			}
+		}
+	}
+
+	let (axis, boundary) = best?;
+	Some(partition(indices, |index| bin_of(index, axis) < boundary))
+}
+
+/// Splits `indices` in half at the median center along the longest axis of the centers.
+fn median_split(centers: &[Vector3], indices: &mut [u32]) -> usize {
+	let (mut min, mut max) = (centers[indices[0] as usize], centers[indices[0] as usize]);
+	for index in indices.iter() {
+		min = min.min(centers[*index as usize]);
+		max = max.max(centers[*index as usize]);
+	}
+	let size = max - min;
+	let axis = if size.x >= size.y && size.x >= size.z { 0 } else if size.y >= size.z { 1 } else { 2 };
+	let key = |index: &u32| {
+		let center = centers[*index as usize];
+		[center.x, center.y, center.z][axis]
+	};
+	let middle = indices.len() / 2;
+	indices.select_nth_unstable_by(middle, |a, b| key(a).total_cmp(&key(b)));
+	middle
+}
+
+fn merge(union: Option<Aabb>, bounds: Option<Aabb>) -> Option<Aabb> {
+	match (union, bounds) {
+		(Some(union), Some(bounds)) => Some(union.union(bounds)),
+		(union, bounds) => union.or(bounds),
+	}
+}
+
+/// Moves the indices matching `first` to the front and returns how many there are.
+fn partition(indices: &mut [u32], first: impl Fn(u32) -> bool) -> usize {
+	let mut split = 0;
+	for i in 0..indices.len() {
+		if first(indices[i]) {
+			indices.swap(i, split);
+			split += 1;
+		}
+	}
+	split
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::collision::{Bvh, BvhNode, Ray, Sphere};
+	use crate::{Aabb, Vector3};
+	use alloc::vec::Vec;
+	use rand::rngs::SmallRng;
+	use rand::{Rng, SeedableRng};
+
+	fn boxes(count: usize, seed: u64) -> Vec<Aabb> {
+		let mut rng = SmallRng::seed_from_u64(seed);
+		(0..count)
+			.map(|_| {
+				let min = Vector3::new(rng.random_range(-50.0..50.0), rng.random_range(-50.0..50.0), rng.random_range(-50.0..50.0));
+				let size = Vector3::new(rng.random_range(0.0..4.0), rng.random_range(0.0..4.0), rng.random_range(0.0..4.0));
+				Aabb::new(min, min + size)
+			})
+			.collect()
+	}
+
+	fn sorted(mut indices: Vec<usize>) -> Vec<usize> {
+		indices.sort_unstable();
+		indices
+	}
+
+	#[test]
+	fn queries_match_brute_force() {
+		let bounds = boxes(500, 7);
+		let bvh = Bvh::build(&bounds);
+		assert_eq!(core::mem::size_of::<BvhNode>(), 32);
+		let mut indices: Vec<u32> = bvh.indices().to_vec();
+		indices.sort_unstable();
+		assert_eq!(indices, (0..500).collect::<Vec<u32>>());
+
+		let mut rng = SmallRng::seed_from_u64(8);
+		for _ in 0..200 {
+			let origin = Vector3::new(rng.random_range(-60.0..60.0), rng.random_range(-60.0..60.0), rng.random_range(-60.0..60.0));
+			let direction = Vector3::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0));
+			let ray = Ray::new(origin, direction);
+			let hit = |i: usize| ray.intersect_aabb(&bounds[i]);
+			let expected = (0..bounds.len())
+				.filter_map(|i| hit(i).map(|distance| (i, distance)))
+				.filter(|(_, distance)| *distance <= 80.0)
+				.min_by(|a, b| a.1.total_cmp(&b.1));
+			assert_eq!(bvh.cast_ray(&ray, 80.0, hit).map(|(_, distance)| distance), expected.map(|(_, distance)| distance));
+			let any = bvh.cast_ray_any(&ray, 80.0, hit);
+			assert_eq!(any.is_some(), expected.is_some());
+			assert!(any.is_none_or(|(i, distance)| hit(i) == Some(distance) && distance <= 80.0));
+
+			let query = Aabb::new(origin, origin + direction.abs() * 20.0);
+			let mut found = Vec::new();
+			bvh.overlap_aabb(&query, |i| found.push(i));
+			assert_eq!(sorted(found), (0..bounds.len()).filter(|i| bounds[*i].intersects(&query)).collect::<Vec<_>>());
+
+			let sphere = Sphere::new(origin, 15.0);
+			let mut found = Vec::new();
+			bvh.overlap_sphere(&sphere, |i| found.push(i));
+			let expected: Vec<usize> = (0..bounds.len()).filter(|i| bounds[*i].distance(sphere.center) <= 15.0).collect();
+			assert_eq!(sorted(found), expected);
+		}
+	}
+
+	#[test]
+	fn refit() {
+		let mut bounds = boxes(100, 3);
+		let mut bvh = Bvh::build(&bounds);
+		for (i, bounds) in bounds.iter_mut().enumerate() {
+			let offset = Vector3::new(i as f32, 0.0, -(i as f32));
+			*bounds = Aabb::new(bounds.min + offset, bounds.max + offset);
+		}
+		bvh.refit(&bounds);
+		assert_eq!(bvh.bounds(), Aabb::from_points(&bounds.iter().flat_map(|b| [b.min, b.max]).collect::<Vec<_>>()));
+		for node in bvh.nodes().iter().filter(|node| node.is_leaf()) {
+			let offset = node.offset as usize;
+			for index in &bvh.indices()[offset..offset + node.count as usize] {
+				let bounds = bounds[*index as usize];
+				assert!(node.bounds().contains_point(bounds.min) && node.bounds().contains_point(bounds.max));
+			}
+		}
+
+		let ray = Ray::new(Vector3::new(99.0 - 200.0, 0.0, -99.0), Vector3::RIGHT);
+		let hit = bvh.cast_ray(&ray, f32::INFINITY, |i| ray.intersect_aabb(&bounds[i]));
+		assert_eq!(hit, (0..100).filter_map(|i| ray.intersect_aabb(&bounds[i]).map(|d| (i, d))).min_by(|a, b| a.1.total_cmp(&b.1)));
+	}
+
+	#[test]
+	fn degenerate() {
+		let empty = Bvh::build(&[]);
+		assert_eq!(empty.bounds(), None);
+		assert_eq!(empty.cast_ray(&Ray::new(Vector3::ZERO, Vector3::UP), f32::INFINITY, |_| Some(0.0)), None);
+		empty.overlap_sphere(&Sphere::new(Vector3::ZERO, 1.0), |_| panic!());
+
+		// Identical boxes cannot be separated by area, they are split by count
+		let same = [Aabb::new(Vector3::ZERO, Vector3::ONE); 1000];
+		let bvh = Bvh::build(&same);
+		assert!(bvh.nodes().iter().all(|node| node.count <= 8));
+		let mut count = 0;
+		bvh.overlap_aabb(&Aabb::new(Vector3::splat(0.5), Vector3::splat(0.5)), |_| count += 1);
+		assert_eq!(count, 1000);
+
+		// Exponentially spaced boxes would make SAH build a list
+		let spread: Vec<Aabb> = (0..2000).map(|i| {
+			let x = 1.01f32.powi(i);
+			Aabb::new(Vector3::new(x, 0.0, 0.0), Vector3::new(x, 1.0, 1.0))
+		}).collect();
+		let bvh = Bvh::build(&spread);
+		let ray = Ray::new(Vector3::new(-1.0, 0.5, 0.5), Vector3::RIGHT);
+		assert_eq!(bvh.cast_ray(&ray, f32::INFINITY, |i| ray.intersect_aabb(&spread[i])), Some((0, 2.0)));
+
+		// Blocks on the floor, found by a ray sliding along it
+		let floor: Vec<Aabb> = (0..100).map(|i| {
+			let corner = Vector3::new((i % 10) as f32, 0.0, (i / 10) as f32);
+			Aabb::new(corner, corner + Vector3::ONE)
+		}).collect();
+		let bvh = Bvh::build(&floor);
+		let ray = Ray::new(Vector3::new(-2.0, 0.0, 4.5), Vector3::RIGHT);
+		assert_eq!(bvh.cast_ray(&ray, f32::INFINITY, |i| ray.intersect_aabb(&floor[i])), Some((40, 2.0)));
+	}
+}
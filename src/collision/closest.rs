@@ -0,0 +1,235 @@
+use crate::collision::Support;
+use crate::Vector3;
+
+/// A line segment from `start` to `end`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment {
+	pub start: Vector3,
+	pub end: Vector3,
+}
+
+impl Segment {
+	pub const fn new(start: Vector3, end: Vector3) -> Self {
+		Segment { start, end }
+	}
+
+	/// Where the point closest to `point` is, from 0 at `start` to 1 at `end`.
+	pub fn closest_parameter(&self, point: Vector3) -> f32 {
+		let direction = self.end - self.start;
+		let length_squared = direction.dot(direction);
+		if length_squared > 0.0 {
+			((point - self.start).dot(direction) / length_squared).clamp(0.0, 1.0)
+		} else {
+			0.0
+		}
+	}
+
+	pub fn closest_point(&self, point: Vector3) -> Vector3 {
+		self.start + (self.end - self.start) * self.closest_parameter(point)
+	}
+
+	pub fn distance_squared(&self, point: Vector3) -> f32 {
+		let offset = point - self.closest_point(point);
+		offset.dot(offset)
+	}
+
+	pub fn distance(&self, point: Vector3) -> f32 {
+		libm::sqrtf(self.distance_squared(point))
+	}
+
+	/// The closest points between `self` and `other`, in that order. Parallel segments have many
+	/// pairs of closest points, one of them is returned.
+	pub fn closest_points(&self, other: &Segment) -> (Vector3, Vector3) {
+		// Ericson, Real-Time Collision Detection 5.1.9
+		let d1 = self.end - self.start;
+		let d2 = other.end - other.start;
+		let r = self.start - other.start;
+		let a = d1.dot(d1);
+		let e = d2.dot(d2);
+		let f = d2.dot(r);
+
+		let (s, t) = if a <= 0.0 && e <= 0.0 {
+			(0.0, 0.0)
+		} else if a <= 0.0 {
+			(0.0, (f / e).clamp(0.0, 1.0))
+		} else {
+			let c = d1.dot(r);
+			if e <= 0.0 {
+				((-c / a).clamp(0.0, 1.0), 0.0)
+			} else {
+				let b = d1.dot(d2);
+				let denominator = a * e - b * b;
+				let s = if denominator > f32::EPSILON * a * e { ((b * f - c * e) / denominator).clamp(0.0, 1.0) } else { 0.0 };
+				let t = (b * s + f) / e;
+				if t < 0.0 {
+					((-c / a).clamp(0.0, 1.0), 0.0)
+				} else if t > 1.0 {
+					(((b - c) / a).clamp(0.0, 1.0), 1.0)
+				} else {
+					(s, t)
+				}
+			}
+		};
+		(self.start + d1 * s, other.start + d2 * t)
+	}
+
+	pub fn segment_distance_squared(&self, other: &Segment) -> f32 {
+		let (a, b) = self.closest_points(other);
+		(b - a).dot(b - a)
+	}
+
+	pub fn segment_distance(&self, other: &Segment) -> f32 {
+		libm::sqrtf(self.segment_distance_squared(other))
+	}
+}
+
+impl Support for Segment {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		if (self.end - self.start).dot(direction) > 0.0 { self.end } else { self.start }
+	}
+}
+
+/// A triangle, its front face is the side `a`, `b` and `c` appear counter-clockwise from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle {
+	pub a: Vector3,
+	pub b: Vector3,
+	pub c: Vector3,
+}
+
+impl Triangle {
+	pub const fn new(a: Vector3, b: Vector3, c: Vector3) -> Self {
+		Triangle { a, b, c }
+	}
+
+	/// The front face's normal, with a length of twice the triangle's area.
+	pub const fn normal(&self) -> Vector3 {
+		self.b.sub(self.a).cross(self.c.sub(self.a))
+	}
+
+	/// Weights of `a`, `b` and `c` that give `point` projected onto the triangle's plane.
+	/// They are all in `[0, 1]` when the projection is inside the triangle. `None` when the
+	/// triangle is degenerate.
+	pub fn barycentric(&self, point: Vector3) -> Option<[f32; 3]> {
+		let (ab, ac, ap) = (self.b - self.a, self.c - self.a, point - self.a);
+		let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+		let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+		let denominator = d00 * d11 - d01 * d01;
+		if denominator <= f32::EPSILON * d00 * d11 || denominator <= 0.0 {
+			return None;
+		}
+		let v = (d11 * d20 - d01 * d21) / denominator;
+		let w = (d00 * d21 - d01 * d20) / denominator;
+		Some([1.0 - v - w, v, w])
+	}
+
+	pub fn closest_point(&self, point: Vector3) -> Vector3 {
+		// Ericson, Real-Time Collision Detection 5.1.5, with guards for degenerate triangles
+		let (a, b, c) = (self.a, self.b, self.c);
+		let ab = b - a;
+		let ac = c - a;
+		let d1 = ab.dot(point - a);
+		let d2 = ac.dot(point - a);
+		if d1 <= 0.0 && d2 <= 0.0 {
+			return a;
+		}
+
+		let d3 = ab.dot(point - b);
+		let d4 = ac.dot(point - b);
+		if d3 >= 0.0 && d4 <= d3 {
+			return b;
+		}
+
+		let vc = d1 * d4 - d3 * d2;
+		if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 && d1 - d3 > 0.0 {
+			return a + ab * (d1 / (d1 - d3));
+		}
+
+		let d5 = ab.dot(point - c);
+		let d6 = ac.dot(point - c);
+		if d6 >= 0.0 && d5 <= d6 {
+			return c;
+		}
+
+		let vb = d5 * d2 - d1 * d6;
+		if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 && d2 - d6 > 0.0 {
+			return a + ac * (d2 / (d2 - d6));
+		}
+
+		let va = d3 * d6 - d5 * d4;
+		if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 && (d4 - d3) + (d5 - d6) > 0.0 {
+			return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+		}
+
+		let denominator = va + vb + vc;
+		if denominator > 0.0 {
+			return a + ab * (vb / denominator) + ac * (vc / denominator);
+		}
+		// Collinear corners, the triangle is its longest edge
+		[Segment::new(a, b), Segment::new(b, c), Segment::new(c, a)]
+			.map(|edge| edge.closest_point(point))
+			.into_iter()
+			.fold(a, |best, candidate| if (candidate - point).dot(candidate - point) < (best - point).dot(best - point) { candidate } else { best })
+	}
+
+	pub fn distance_squared(&self, point: Vector3) -> f32 {
+		let offset = point - self.closest_point(point);
+		offset.dot(offset)
+	}
+
+	pub fn distance(&self, point: Vector3) -> f32 {
+		libm::sqrtf(self.distance_squared(point))
+	}
+}
+
+impl Support for Triangle {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		[self.a, self.b, self.c].support(direction)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::collision::{Segment, Triangle};
+	use crate::Vector3;
+
+	#[test]
+	fn segments() {
+		let segment = Segment::new(Vector3::ZERO, Vector3::new(4.0, 0.0, 0.0));
+		assert_eq!(segment.closest_point(Vector3::new(1.0, 3.0, 0.0)), Vector3::new(1.0, 0.0, 0.0));
+		assert_eq!(segment.closest_point(Vector3::new(-2.0, 1.0, 0.0)), Vector3::ZERO);
+		assert_eq!(segment.distance_squared(Vector3::new(7.0, 4.0, 0.0)), 25.0);
+		assert_eq!(Segment::new(Vector3::ONE, Vector3::ONE).distance(Vector3::new(1.0, 1.0, 3.0)), 2.0);
+
+		// Crossing, parallel and end to end
+		let crossing = Segment::new(Vector3::new(2.0, 1.0, -1.0), Vector3::new(2.0, 1.0, 1.0));
+		assert_eq!(segment.closest_points(&crossing), (Vector3::new(2.0, 0.0, 0.0), Vector3::new(2.0, 1.0, 0.0)));
+		let parallel = Segment::new(Vector3::new(1.0, 2.0, 0.0), Vector3::new(6.0, 2.0, 0.0));
+		assert_eq!(segment.segment_distance(&parallel), 2.0);
+		let beyond = Segment::new(Vector3::new(6.0, 0.0, 0.0), Vector3::new(9.0, 1.0, 0.0));
+		assert_eq!(segment.closest_points(&beyond), (Vector3::new(4.0, 0.0, 0.0), Vector3::new(6.0, 0.0, 0.0)));
+		let point = Segment::new(Vector3::new(1.0, -1.0, 0.0), Vector3::new(1.0, -1.0, 0.0));
+		assert_eq!(point.closest_points(&segment), (Vector3::new(1.0, -1.0, 0.0), Vector3::new(1.0, 0.0, 0.0)));
+	}
+
+	#[test]
+	fn triangles() {
+		let triangle = Triangle::new(Vector3::ZERO, Vector3::new(4.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 4.0));
+		assert_eq!(triangle.normal(), Vector3::new(0.0, -16.0, 0.0));
+		assert_eq!(triangle.closest_point(Vector3::new(1.0, 5.0, 1.0)), Vector3::new(1.0, 0.0, 1.0));
+		assert_eq!(triangle.closest_point(Vector3::new(-1.0, 0.0, -1.0)), Vector3::ZERO);
+		assert_eq!(triangle.closest_point(Vector3::new(2.0, 0.0, -3.0)), Vector3::new(2.0, 0.0, 0.0));
+		assert_eq!(triangle.closest_point(Vector3::new(3.0, 1.0, 3.0)), Vector3::new(2.0, 0.0, 2.0));
+		assert_eq!(triangle.distance_squared(Vector3::new(6.0, 0.0, 0.0)), 4.0);
+
+		assert_eq!(triangle.barycentric(Vector3::new(1.0, 7.0, 2.0)), Some([0.25, 0.25, 0.5]));
+		assert_eq!(triangle.barycentric(Vector3::new(-4.0, 0.0, 0.0)), Some([2.0, -1.0, 0.0]));
+
+		let collinear = Triangle::new(Vector3::ZERO, Vector3::new(1.0, 0.0, 0.0), Vector3::new(3.0, 0.0, 0.0));
+		assert_eq!(collinear.barycentric(Vector3::ONE), None);
+		assert_eq!(collinear.closest_point(Vector3::new(2.0, 1.0, 0.0)), Vector3::new(2.0, 0.0, 0.0));
+		assert_eq!(collinear.closest_point(Vector3::new(5.0, 0.0, 0.0)), Vector3::new(3.0, 0.0, 0.0));
+		let point = Triangle::new(Vector3::ONE, Vector3::ONE, Vector3::ONE);
+		assert_eq!(point.closest_point(Vector3::ZERO), Vector3::ONE);
+	}
+}
@@ -0,0 +1,337 @@
+use crate::collision::gjk::{cores, support, Cores, Simplex, Vertex, MAX_ITERATIONS, TOLERANCE};
+use crate::collision::shape::{extent, Core, Support};
+use crate::{Quaternion, Vector3};
+use libm::{fabsf, fmaxf};
+
+const MAX_VERTICES: usize = 64;
+/// A convex polytope with `V` vertices has at most `2V - 4` triangles.
+const MAX_FACES: usize = 2 * MAX_VERTICES;
+/// Expansion stops once a step deepens the polytope by less than this fraction of the shapes'
+/// size. Curved cores keep yielding new vertices, much closer than this and the slivers of
+/// faces between them have normals too imprecise to keep the polytope convex.
+const DEPTH_TOLERANCE: f32 = 1e-5;
+
+const AXES: [Vector3; 6] = [Vector3::RIGHT, Vector3::LEFT, Vector3::UP, Vector3::DOWN, Vector3::FORWARD, Vector3::BACK];
+
+/// How deeply two overlapping shapes penetrate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact {
+	/// Unit direction from `a` towards `b`, moving `b` by `normal * depth` separates the shapes.
+	pub normal: Vector3,
+	pub depth: f32,
+	/// The point of `a` deepest inside `b`.
+	pub point_a: Vector3,
+	/// The point of `b` deepest inside `a`.
+	pub point_b: Vector3,
+}
+
+/// Penetration depth and contact normal of two shapes, `None` when they are separated.
+/// Touching shapes give a depth of zero.
+pub fn penetration<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> Option<Contact> {
+	let (margin_a, margin_b) = (a.margin(), b.margin());
+	let core = match cores(a, b) {
+		// Only the margins overlap, the cores' closest points give the normal exactly
+		Cores::Separated(separation) if separation.distance <= margin_a + margin_b => Contact {
+			normal: separation.normal,
+			depth: -separation.distance,
+			point_a: separation.point_a,
+			point_b: separation.point_b,
+		},
+		Cores::Separated(_) => return None,
+		Cores::Overlapping(simplex) => expand(&Core(a), &Core(b), simplex),
+	};
+	Some(Contact {
+		normal: core.normal,
+		depth: core.depth + margin_a + margin_b,
+		point_a: core.point_a + core.normal * margin_a,
+		point_b: core.point_b - core.normal * margin_b,
+	})
+}
+
+/// A fixed capacity list, so EPA runs without an allocator.
+struct List<T, const N: usize> {
+	items: [T; N],
+	len: usize,
+}
+
+impl<T: Copy, const N: usize> List<T, N> {
+	fn new(fill: T) -> Self {
+		List { items: [fill; N], len: 0 }
+	}
+
+	/// False when the list is full.
+	fn push(&mut self, item: T) -> bool {
+		if self.len == N {
+			return false;
+		}
+		self.items[self.len] = item;
+		self.len += 1;
+		true
+	}
+
+	fn swap_remove(&mut self, index: usize) {
+		self.len -= 1;
+		self.items[index] = self.items[self.len];
+	}
+
+	fn as_slice(&self) -> &[T] {
+		&self.items[..self.len]
+	}
+}
+
+#[derive(Clone, Copy)]
+struct Face {
+	indices: [u8; 3],
+	/// Points out of the polytope.
+	normal: Vector3,
+	/// Distance of the face's plane from the origin.
+	distance: f32,
+}
+
+struct Polytope {
+	vertices: List<Vertex, MAX_VERTICES>,
+	faces: List<Face, MAX_FACES>,
+}
+
+impl Polytope {
+	fn point(&self, index: u8) -> Vector3 {
+		self.vertices.items[index as usize].point
+	}
+
+	/// A face wound counter-clockwise seen from outside. Degenerate faces are never the closest.
+	fn face(&self, indices: [u8; 3]) -> Face {
+		let [a, b, c] = indices.map(|index| self.point(index));
+		let normal = (b - a).cross(c - a);
+		let length = normal.magnitude();
+		if length > f32::MIN_POSITIVE {
+			let normal = normal / length;
+			Face { indices, normal, distance: normal.dot(a) }
+		} else {
+			Face { indices, normal: Vector3::ZERO, distance: f32::INFINITY }
+		}
+	}
+
+	fn closest(&self) -> Face {
+		let faces = self.faces.as_slice();
+		faces[1..].iter().fold(faces[0], |best, face| if face.distance < best.distance { *face } else { best })
+	}
+
+	fn contact(&self, face: Face) -> Contact {
+		let [a, b, c] = face.indices.map(|index| self.vertices.items[index as usize]);
+		let [u, v, w] = barycentric(face.normal * face.distance, a.point, b.point, c.point);
+		Contact {
+			normal: face.normal,
+			depth: fmaxf(face.distance, 0.0),
+			point_a: a.a * u + b.a * v + c.a * w,
+			point_b: a.b * u + b.b * v + c.b * w,
+		}
+	}
+}
+
+/// Barycentric coordinates of `point` projected onto the plane of the triangle.
+fn barycentric(point: Vector3, a: Vector3, b: Vector3, c: Vector3) -> [f32; 3] {
+	let (ab, ac, ap) = (b - a, c - a, point - a);
+	let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+	let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+	let denominator = d00 * d11 - d01 * d01;
+	if denominator <= f32::MIN_POSITIVE {
+		return [1.0, 0.0, 0.0];
+	}
+	let v = (d11 * d20 - d01 * d21) / denominator;
+	let w = (d00 * d21 - d01 * d20) / denominator;
+	[1.0 - v - w, v, w]
+}
+
+/// Grows the simplex GJK ended with into a tetrahedron, then expands it towards the boundary of
+/// the Minkowski difference until the face nearest the origin stops moving.
+fn expand<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B, simplex: Simplex) -> Contact {
+	let scale = AXES.iter().fold(0.0, |scale, axis| fmaxf(scale, extent(support(a, b, *axis).point)));
+	let epsilon = TOLERANCE * scale;
+
+	// GJK stops as soon as the origin is touched, which may be by a point, segment or triangle
+	let mut vertices = [Vertex::EMPTY; 4];
+	let mut len = simplex.vertices().len();
+	vertices[..len].copy_from_slice(simplex.vertices());
+	let mut normal = Vector3::UP;
+	if len == 1 {
+		for axis in AXES {
+			let vertex = support(a, b, axis);
+			if (vertex.point - vertices[0].point).magnitude() > epsilon {
+				vertices[1] = vertex;
+				len = 2;
+				break;
+			}
+		}
+	}
+	if len == 2 {
+		let line = (vertices[1].point - vertices[0].point).normalized();
+		// Any axis not parallel to the line gives a perpendicular to search around it with
+		let least = line.abs();
+		let axis = if least.x <= least.y && least.x <= least.z {
+			Vector3::RIGHT
+		} else if least.y <= least.z {
+			Vector3::UP
+		} else {
+			Vector3::FORWARD
+		};
+		let turn = Quaternion::from_axis_angle_degrees(line, 60.0);
+		normal = line.cross(axis).normalized();
+		for _ in 0..6 {
+			let vertex = support(a, b, normal);
+			if (vertex.point - vertices[0].point).cross(line).magnitude() > epsilon {
+				vertices[2] = vertex;
+				len = 3;
+				break;
+			}
+			normal = turn.rotate(normal);
+		}
+	}
+	if len == 3 {
+		normal = (vertices[1].point - vertices[0].point).cross(vertices[2].point - vertices[0].point).normalized();
+		for direction in [normal, -normal] {
+			let vertex = support(a, b, direction);
+			if fabsf((vertex.point - vertices[0].point).dot(normal)) > epsilon {
+				vertices[3] = vertex;
+				len = 4;
+				break;
+			}
+		}
+	}
+	if len < 4 {
+		// The Minkowski difference is flat, so the shapes can only touch
+		let (point_a, point_b) = simplex.witnesses();
+		return Contact { normal, depth: 0.0, point_a, point_b };
+	}
+
+	let mut polytope = Polytope { vertices: List::new(Vertex::EMPTY), faces: List::new(Polytope::EMPTY_FACE) };
+	for vertex in vertices {
+		polytope.vertices.push(vertex);
+	}
+	for [i, j, k, opposite] in [[0, 1, 2, 3], [0, 3, 1, 2], [0, 2, 3, 1], [1, 3, 2, 0]] {
+		let face = polytope.face([i, j, k]);
+		if face.normal.dot(polytope.point(opposite) - polytope.point(i)) > 0.0 {
+			polytope.faces.push(polytope.face([i, k, j]));
+		} else {
+			polytope.faces.push(face);
+		}
+	}
+
+	let mut horizon: List<[u8; 2], { 3 * MAX_FACES }> = List::new([0; 2]);
+	let mut closest = polytope.closest();
+	for _ in 0..MAX_ITERATIONS {
+		let vertex = support(a, b, closest.normal);
+		if vertex.point.dot(closest.normal) - closest.distance <= DEPTH_TOLERANCE * scale || !polytope.vertices.push(vertex) {
+			break;
+		}
+		let index = (polytope.vertices.len - 1) as u8;
+
+		// Remove every face the new vertex can see, the edges bordering them form the horizon
+		horizon.len = 0;
+		let mut i = 0;
+		while i < polytope.faces.len {
+			let face = polytope.faces.items[i];
+			if face.normal.dot(vertex.point - polytope.point(face.indices[0])) > epsilon {
+				let [p, q, r] = face.indices;
+				for [from, to] in [[p, q], [q, r], [r, p]] {
+					match horizon.as_slice().iter().position(|edge| *edge == [to, from]) {
+						Some(shared) => horizon.swap_remove(shared),
+						None => {
+							horizon.push([from, to]);
+						}
+					}
+				}
+				polytope.faces.swap_remove(i);
+			} else {
+				i += 1;
+			}
+		}
+
+		// Out of room, or rounding left the polytope no longer convex, which shows as the nearest
+		// face moving closer. Either way the last closed polytope is the best estimate.
+		let previous = closest;
+		let contact = polytope.contact(previous);
+		for [from, to] in horizon.as_slice() {
+			if !polytope.faces.push(polytope.face([*from, *to, index])) {
+				return contact;
+			}
+		}
+		closest = polytope.closest();
+		if closest.distance < previous.distance - epsilon {
+			return contact;
+		}
+	}
+	polytope.contact(closest)
+}
+
+impl Polytope {
+	const EMPTY_FACE: Face = Face { indices: [0; 3], normal: Vector3::ZERO, distance: f32::INFINITY };
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::collision::{penetration, Capsule, Contact, Obb, Sphere, Support};
+	use crate::{Aabb, Quaternion, Transform, Vector3};
+
+	#[test]
+	fn depth_and_normal() {
+		let a = Sphere::new(Vector3::ZERO, 1.0);
+		let b = Sphere::new(Vector3::new(1.5, 0.0, 0.0), 1.0);
+		let contact = penetration(&a, &b).unwrap();
+		assert_eq!(contact, Contact { normal: Vector3::RIGHT, depth: 0.5, point_a: Vector3::RIGHT, point_b: Vector3::new(0.5, 0.0, 0.0) });
+		assert_eq!(penetration(&a, &Sphere::new(Vector3::new(2.5, 0.0, 0.0), 1.0)), None);
+
+		let unit = Aabb::new(Vector3::splat(-1.0), Vector3::ONE);
+		let contact = penetration(&unit, &unit.transformed(Transform::from_translation(Vector3::new(0.25, 1.75, 0.5)))).unwrap();
+		assert!((contact.depth - 0.25).abs() < 1e-5);
+		assert!((contact.normal - Vector3::UP).magnitude() < 1e-5);
+
+		let turned = Obb::new(Vector3::new(0.0, -2.2, 0.0), Vector3::ONE, Quaternion::from_axis_angle_degrees(Vector3::FORWARD, 45.0));
+		let contact = penetration(&unit, &turned).unwrap();
+		assert!((contact.depth - (core::f32::consts::SQRT_2 - 1.2)).abs() < 1e-5);
+		assert!((contact.normal - Vector3::DOWN).magnitude() < 1e-5);
+		// Edge against face, the witness may be anywhere along the edge
+		assert!(contact.point_b.x.abs() < 1e-4 && (contact.point_b.y - (core::f32::consts::SQRT_2 - 2.2)).abs() < 1e-4);
+
+		let capsule = Capsule::new(Vector3::new(-3.0, 0.0, 1.2), Vector3::new(3.0, 0.0, 1.2), 0.5);
+		let contact = penetration(&unit, &capsule).unwrap();
+		assert!((contact.depth - 0.3).abs() < 1e-6);
+		assert_eq!(contact.normal, Vector3::FORWARD);
+
+		// A scaled sphere is no longer round, so EPA has to iterate on its surface
+		let ellipsoid = Sphere::new(Vector3::ZERO, 1.0).transformed(Transform::new(Vector3::new(0.0, 1.5, 0.0), Quaternion::IDENTITY, Vector3::new(2.0, 1.0, 2.0)));
+		let contact = penetration(&unit, &ellipsoid).unwrap();
+		assert!((contact.depth - 0.5).abs() < 1e-4);
+		assert!((contact.normal - Vector3::UP).magnitude() < 1e-2);
+	}
+
+	#[test]
+	fn degenerate() {
+		// Concentric shapes leave GJK with the origin at the center of the difference
+		let unit = Aabb::new(Vector3::splat(-1.0), Vector3::ONE);
+		let contact = penetration(&unit, &unit).unwrap();
+		assert!((contact.depth - 2.0).abs() < 1e-5);
+		assert!((contact.normal.magnitude() - 1.0).abs() < 1e-5);
+		let ball = Sphere::new(Vector3::ONE, 0.5);
+		assert_eq!(penetration(&ball, &ball).unwrap().depth, 1.0);
+
+		// Crossing capsules have flat cores
+		let across = Capsule::new(Vector3::new(-1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0), 0.25);
+		let along = Capsule::new(Vector3::new(0.0, 0.0, -1.0), Vector3::new(0.0, 0.0, 1.0), 0.5);
+		let contact = penetration(&across, &along).unwrap();
+		assert_eq!((contact.depth, contact.normal.y.abs()), (0.75, 1.0));
+
+		// Touching at a single point, edge or face
+		for offset in [Vector3::new(2.0, 2.0, 2.0), Vector3::new(2.0, 2.0, 0.0), Vector3::new(2.0, 0.0, 0.0)] {
+			let contact = penetration(&unit, &unit.transformed(Transform::from_translation(offset))).unwrap();
+			assert!(contact.depth < 1e-5);
+		}
+
+		// Flat shapes have no volume to expand into
+		let square = [Vector3::new(-1.0, 0.0, -1.0), Vector3::new(1.0, 0.0, -1.0), Vector3::new(1.0, 0.0, 1.0), Vector3::new(-1.0, 0.0, 1.0)];
+		let contact = penetration(&square, &square.map(|point| point * 0.5)).unwrap();
+		assert_eq!(contact.depth, 0.0);
+		assert_eq!(contact.normal.y.abs(), 1.0);
+		let point = Vector3::new(0.5, 0.0, 0.5);
+		assert_eq!(penetration(&point, &point).unwrap().depth, 0.0);
+	}
+}
@@ -0,0 +1,302 @@
+use crate::collision::shape::{extent, Core, Support};
+use crate::Vector3;
+use libm::{fabsf, fmaxf};
+
+/// GJK converges in a few steps on polytopes but only approaches curved shapes, this bounds it.
+pub(crate) const MAX_ITERATIONS: usize = 64;
+/// Relative precision the queries stop at.
+pub(crate) const TOLERANCE: f32 = 1e-6;
+
+/// A point of the Minkowski difference `a - b` along with the support points it came from.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Vertex {
+	pub point: Vector3,
+	pub a: Vector3,
+	pub b: Vector3,
+}
+
+impl Vertex {
+	pub const EMPTY: Vertex = Vertex { point: Vector3::ZERO, a: Vector3::ZERO, b: Vector3::ZERO };
+}
+
+pub(crate) fn support<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B, direction: Vector3) -> Vertex {
+	let (a, b) = (a.support(direction), b.support(-direction));
+	Vertex { point: a - b, a, b }
+}
+
+/// Up to four vertices, with their point closest to the origin and its barycentric coordinates.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Simplex {
+	vertices: [Vertex; 4],
+	weights: [f32; 4],
+	len: usize,
+	closest: Vector3,
+}
+
+impl Simplex {
+	fn new(vertices: &[Vertex], weights: &[f32], closest: Vector3) -> Simplex {
+		let mut simplex = Simplex { vertices: [Vertex::EMPTY; 4], weights: [0.0; 4], len: vertices.len(), closest };
+		simplex.vertices[..vertices.len()].copy_from_slice(vertices);
+		simplex.weights[..weights.len()].copy_from_slice(weights);
+		simplex
+	}
+
+	fn point(vertex: Vertex) -> Simplex {
+		Simplex::new(&[vertex], &[1.0], vertex.point)
+	}
+
+	pub fn vertices(&self) -> &[Vertex] {
+		&self.vertices[..self.len]
+	}
+
+	/// The points of `a` and `b` closest to each other, meaningless once a tetrahedron encloses the origin.
+	pub fn witnesses(&self) -> (Vector3, Vector3) {
+		self.vertices().iter().zip(self.weights).fold((Vector3::ZERO, Vector3::ZERO), |(a, b), (vertex, weight)| {
+			(a + vertex.a * weight, b + vertex.b * weight)
+		})
+	}
+
+	/// Adds `vertex` and reduces to the smallest simplex holding the point closest to the origin.
+	/// All four vertices are kept when they enclose the origin.
+	fn push(&self, vertex: Vertex) -> Simplex {
+		let [a, b, c, _] = self.vertices;
+		match self.len {
+			0 => Simplex::point(vertex),
+			1 => segment(a, vertex),
+			2 => triangle(a, b, vertex),
+			_ => tetrahedron(a, b, c, vertex),
+		}
+	}
+
+	fn distance_squared(&self) -> f32 {
+		self.closest.dot(self.closest)
+	}
+}
+
+fn nearer(best: Option<Simplex>, candidate: Simplex) -> Option<Simplex> {
+	match best {
+		Some(best) if best.distance_squared() <= candidate.distance_squared() => Some(best),
+		_ => Some(candidate),
+	}
+}
+
+fn segment(a: Vertex, b: Vertex) -> Simplex {
+	let ab = b.point - a.point;
+	let length_squared = ab.dot(ab);
+	let t = if length_squared > 0.0 { -a.point.dot(ab) / length_squared } else { 0.0 };
+	if t <= 0.0 {
+		Simplex::point(a)
+	} else if t >= 1.0 {
+		Simplex::point(b)
+	} else {
+		Simplex::new(&[a, b], &[1.0 - t, t], a.point + ab * t)
+	}
+}
+
+// The regions are told apart with each edge's outward perpendicular in the triangle's plane and
+// the face case projects straight onto the plane. Unlike the textbook barycentric region tests
+// nothing here cancels catastrophically on the long thin triangles GJK builds near curved shapes.
+fn triangle(a: Vertex, b: Vertex, c: Vertex) -> Simplex {
+	let ab = b.point - a.point;
+	let ac = c.point - a.point;
+	let normal = ab.cross(ac);
+	let normal_squared = normal.dot(normal);
+	// Collinear or repeated points have no face region
+	let flat = normal_squared <= f32::EPSILON * ab.dot(ab) * ac.dot(ac);
+
+	let mut best = None;
+	for (p, q) in [(a, b), (b, c), (c, a)] {
+		// Points away from the third vertex
+		let outward = (q.point - p.point).cross(normal);
+		if flat || outward.dot(p.point) < 0.0 {
+			best = nearer(best, segment(p, q));
+		}
+	}
+	if let Some(best) = best {
+		return best;
+	}
+
+	let closest = normal * (normal.dot(a.point) / normal_squared);
+	let (pa, pb, pc) = (a.point - closest, b.point - closest, c.point - closest);
+	let u = pb.cross(pc).dot(normal) / normal_squared;
+	let v = pc.cross(pa).dot(normal) / normal_squared;
+	Simplex::new(&[a, b, c], &[u, v, 1.0 - u - v], closest)
+}
+
+fn tetrahedron(a: Vertex, b: Vertex, c: Vertex, d: Vertex) -> Simplex {
+	let (ab, ac, ad) = (b.point - a.point, c.point - a.point, d.point - a.point);
+	let volume = ab.dot(ac.cross(ad));
+	// A flat tetrahedron has no inside, so every face has to be considered
+	let flat = fabsf(volume) <= f32::EPSILON * ab.magnitude() * ac.magnitude() * ad.magnitude();
+
+	let mut best = None;
+	for (p, q, r, opposite) in [(a, b, c, d), (a, c, d, b), (a, d, b, c), (b, d, c, a)] {
+		let normal = (q.point - p.point).cross(r.point - p.point);
+		let origin_side = -normal.dot(p.point);
+		let opposite_side = normal.dot(opposite.point - p.point);
+		if flat || origin_side * opposite_side < 0.0 {
+			best = nearer(best, triangle(p, q, r));
+		}
+	}
+	best.unwrap_or(Simplex::new(&[a, b, c, d], &[0.25; 4], Vector3::ZERO))
+}
+
+enum Outcome {
+	Separated(Simplex),
+	/// The simplex encloses or touches the origin.
+	Overlapping(Simplex),
+}
+
+fn gjk<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> Outcome {
+	let mut simplex = Simplex::point(support(a, b, Vector3::RIGHT));
+	let mut closest = simplex.closest;
+	// A positive `closest.dot(vertex)` proves a plane separates the origin from the difference,
+	// which overrides what rounding may suggest about the simplex
+	let mut separating = false;
+	for _ in 0..MAX_ITERATIONS {
+		let distance_squared = closest.dot(closest);
+		let scale = simplex.vertices().iter().fold(0.0, |scale, vertex| fmaxf(scale, extent(vertex.point)));
+		if distance_squared <= (TOLERANCE * scale) * (TOLERANCE * scale) {
+			return Outcome::Overlapping(simplex);
+		}
+
+		// `closest.dot(vertex) / |closest|` bounds the distance from below, `|closest|` from above
+		let vertex = support(a, b, -closest);
+		let lower = closest.dot(vertex.point);
+		separating = lower > 0.0;
+		if distance_squared - lower <= TOLERANCE * distance_squared {
+			return Outcome::Separated(simplex);
+		}
+
+		let next = simplex.push(vertex);
+		let next_closest = next.closest;
+		if next.len == 4 && !separating {
+			return Outcome::Overlapping(next);
+		}
+		// Rounding can stall progress, the previous simplex is then the best answer
+		if next.len == 4 || next_closest.dot(next_closest) >= distance_squared {
+			break;
+		}
+		simplex = next;
+		closest = next_closest;
+	}
+	if separating {
+		Outcome::Separated(simplex)
+	} else {
+		Outcome::Overlapping(simplex)
+	}
+}
+
+/// The closest points of two separated shapes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Separation {
+	pub distance: f32,
+	/// Unit direction from `a` towards `b`.
+	pub normal: Vector3,
+	/// The point of `a` closest to `b`.
+	pub point_a: Vector3,
+	/// The point of `b` closest to `a`.
+	pub point_b: Vector3,
+}
+
+/// How the cores of two shapes relate, before their margins are taken into account.
+pub(crate) enum Cores {
+	Separated(Separation),
+	/// The simplex GJK ended with, enclosing or touching the origin.
+	Overlapping(Simplex),
+}
+
+pub(crate) fn cores<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> Cores {
+	match gjk(&Core(a), &Core(b)) {
+		Outcome::Separated(simplex) => {
+			let (point_a, point_b) = simplex.witnesses();
+			let distance = simplex.closest.magnitude();
+			Cores::Separated(Separation { distance, normal: -simplex.closest / distance, point_a, point_b })
+		}
+		Outcome::Overlapping(simplex) => Cores::Overlapping(simplex),
+	}
+}
+
+/// True when the shapes overlap or touch.
+pub fn intersects<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> bool {
+	match cores(a, b) {
+		Cores::Separated(separation) => separation.distance <= a.margin() + b.margin(),
+		Cores::Overlapping(_) => true,
+	}
+}
+
+/// Distance and closest points between two shapes, `None` when they overlap or touch.
+pub fn distance<A: Support + ?Sized, B: Support + ?Sized>(a: &A, b: &B) -> Option<Separation> {
+	let (margin_a, margin_b) = (a.margin(), b.margin());
+	let separation = match cores(a, b) {
+		Cores::Separated(separation) if separation.distance > margin_a + margin_b => separation,
+		_ => return None,
+	};
+	Some(Separation {
+		distance: separation.distance - margin_a - margin_b,
+		normal: separation.normal,
+		point_a: separation.point_a + separation.normal * margin_a,
+		point_b: separation.point_b - separation.normal * margin_b,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::collision::{distance, intersects, Capsule, Obb, Sphere, Support};
+	use crate::{Aabb, Quaternion, Transform, Vector3};
+
+	#[test]
+	fn separated() {
+		let a = Sphere::new(Vector3::ZERO, 1.0);
+		let b = Sphere::new(Vector3::new(3.0, 4.0, 0.0), 2.0);
+		let separation = distance(&a, &b).unwrap();
+		assert!((separation.distance - 2.0).abs() < 1e-6);
+		assert!((separation.normal - Vector3::new(0.6, 0.8, 0.0)).magnitude() < 1e-6);
+		assert!((separation.point_a - Vector3::new(0.6, 0.8, 0.0)).magnitude() < 1e-6);
+		assert!((separation.point_b - Vector3::new(1.8, 2.4, 0.0)).magnitude() < 1e-6);
+
+		// Exact on polytopes, the closest feature is an edge of the box against a face
+		let unit = Aabb::new(Vector3::splat(-1.0), Vector3::ONE);
+		let turned = Obb::new(Vector3::new(4.0, 0.0, 0.0), Vector3::ONE, Quaternion::from_axis_angle_degrees(Vector3::UP, 45.0));
+		let separation = distance(&unit, &turned).unwrap();
+		assert!((separation.distance - (3.0 - core::f32::consts::SQRT_2)).abs() < 1e-5);
+		assert_eq!(separation.point_a.x, 1.0);
+
+		let capsule = Capsule::new(Vector3::new(-5.0, 3.0, 0.0), Vector3::new(5.0, 3.0, 0.0), 0.5);
+		let point = Vector3::new(2.0, 0.0, 0.0);
+		assert!((distance(&capsule, &point).unwrap().distance - 2.5).abs() < 1e-5);
+		assert!(!intersects(&capsule, &point));
+	}
+
+	#[test]
+	fn overlapping() {
+		let unit = Aabb::new(Vector3::splat(-1.0), Vector3::ONE);
+		assert!(intersects(&unit, &Sphere::new(Vector3::new(1.5, 1.5, 0.0), 0.75)));
+		assert!(!intersects(&unit, &Sphere::new(Vector3::new(1.5, 1.5, 0.0), 0.7)));
+		assert!(intersects(&unit, &Vector3::ZERO));
+		assert_eq!(distance(&unit, &unit), None);
+
+		// Touching faces, edges and corners count as overlapping
+		let placed = |x, y, z| unit.transformed(Transform::from_translation(Vector3::new(x, y, z)));
+		assert!(intersects(&unit, &placed(2.0, 0.5, 0.0)));
+		assert!(intersects(&unit, &placed(2.0, 2.0, 0.0)));
+		assert!(intersects(&unit, &placed(2.0, 2.0, -2.0)));
+		assert!(!intersects(&unit, &placed(2.0, 2.0, -2.001)));
+	}
+
+	#[test]
+	fn degenerate() {
+		// Repeated, collinear and coplanar points
+		let triangle = [Vector3::ZERO, Vector3::ZERO, Vector3::new(2.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 2.0), Vector3::new(1.0, 0.0, 1.0)];
+		let segment = [Vector3::new(0.5, -1.0, 0.5), Vector3::new(0.5, 1.0, 0.5), Vector3::new(0.5, 0.0, 0.5)];
+		assert!(intersects(&triangle, &segment));
+		assert!(!intersects(&triangle, &segment.map(|point| point + Vector3::new(2.0, 0.0, 0.0))));
+		let separation = distance(&triangle, &Vector3::new(0.5, 3.0, 0.5)).unwrap();
+		assert!((separation.distance - 3.0).abs() < 1e-6);
+
+		let point = Vector3::new(1.0, 2.0, 3.0);
+		assert!(intersects(&point, &point));
+		assert!((distance(&point, &Vector3::new(1.0, 2.0, 4.0)).unwrap().distance - 1.0).abs() < 1e-6);
+		assert!(intersects(&Sphere::new(point, 0.0), &Capsule::new(Vector3::ZERO, point * 2.0, 0.0)));
+	}
+}
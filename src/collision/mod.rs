@@ -0,0 +1,52 @@
+//! Convex collision detection with GJK and EPA.
+//!
+//! Shapes only need a [`Support`] function, which maps a direction to the shape's furthest point
+//! in it. [`Sphere`], [`Obb`], [`Capsule`], [`Aabb`](crate::Aabb), single points and point
+//! clouds, treated as their convex hull, are provided. [`Support::transformed`] places a shape
+//! with a [`Transform`](crate::Transform) and [`Support::sum`] combines two shapes into their
+//! Minkowski sum.
+//!
+//! [`intersects`] and [`distance`] run GJK, [`penetration`] continues into EPA for the depth and
+//! normal of overlapping shapes. Both work on the shapes' cores, such as a capsule's segment,
+//! and account for the rounding [`Support::margin`] afterwards, so spheres and capsules give
+//! exact results. Nothing allocates: EPA's polytope has room for 64 vertices and returns its best
+//! estimate when that runs out, as both do after 64 iterations on other curved shapes.
+//! Repeated, collinear and coplanar points, touching shapes and flat shapes are all handled,
+//! touching counts as overlapping with a depth of zero.
+//!
+//! Closest point and distance queries against a point are answered exactly by [`Segment`],
+//! [`Triangle`], [`Obb`] and [`Aabb`](crate::Aabb) themselves, with squared variants that skip
+//! the square root. [`Ray`] intersects triangles.
+//!
+//! With the `alloc` feature, [`Bvh`] culls ray casts and overlap queries against many
+//! primitives down to the few whose boxes are involved.
+//!
+//! ```
+//! use maths::collision::{self, Capsule, Support};
+//! use maths::{Aabb, Quaternion, Transform, Vector3};
+//!
+//! let turn = Quaternion::from_axis_angle_degrees(Vector3::UP, 45.0);
+//! let crate_box = Aabb::new(Vector3::splat(-0.5), Vector3::splat(0.5))
+//!     .transformed(Transform::from_rotation_translation(turn, Vector3::new(0.0, 0.5, 0.0)));
+//! let player = Capsule::new(Vector3::new(0.9, 0.5, 0.0), Vector3::new(0.9, 2.0, 0.0), 0.3);
+//!
+//! let contact = collision::penetration(&crate_box, &player).unwrap();
+//! assert!((contact.normal - Vector3::RIGHT).magnitude() < 1e-6);
+//! assert!((contact.depth - (0.5f32.hypot(0.5) - 0.6)).abs() < 1e-6);
+//! ```
+
+#[cfg(feature = "alloc")]
+mod bvh;
+mod closest;
+mod epa;
+mod gjk;
+mod ray;
+mod shape;
+
+#[cfg(feature = "alloc")]
+pub use bvh::{Bvh, BvhNode};
+pub use closest::{Segment, Triangle};
+pub use epa::{penetration, Contact};
+pub use gjk::{distance, intersects, Separation};
+pub use ray::{Culling, Ray, RayHit};
+pub use shape::{Capsule, Obb, Sphere, Sum, Support, Transformed};
@@ -0,0 +1,144 @@
+use crate::collision::Triangle;
+use crate::{Aabb, Vector3};
+
+/// Which sides of a triangle a ray can hit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Culling {
+	/// Both faces are hit.
+	#[default]
+	None,
+	/// Only the front face, where the corners appear counter-clockwise, is hit.
+	Back,
+	/// Only the back face is hit.
+	Front,
+}
+
+/// A half line from `origin` along `direction`, which need not be normalized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+	pub origin: Vector3,
+	pub direction: Vector3,
+}
+
+/// Where a ray hits a triangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+	/// The hit is at [`Ray::at`] this distance, in multiples of the ray's direction.
+	pub distance: f32,
+	/// Barycentric weight of the triangle's `b` corner at the hit.
+	pub u: f32,
+	/// Barycentric weight of the triangle's `c` corner at the hit.
+	pub v: f32,
+}
+
+impl Ray {
+	pub const fn new(origin: Vector3, direction: Vector3) -> Self {
+		Ray { origin, direction }
+	}
+
+	pub const fn at(&self, distance: f32) -> Vector3 {
+		self.origin.add(self.direction.scale(distance))
+	}
+
+	/// Möller–Trumbore intersection. Hits behind the origin and rays parallel to the triangle miss.
+	pub fn intersect_triangle(&self, triangle: &Triangle, culling: Culling) -> Option<RayHit> {
+		let edge1 = triangle.b - triangle.a;
+		let edge2 = triangle.c - triangle.a;
+		let p = self.direction.cross(edge2);
+		// Positive when the ray travels against the normal, onto the front face
+		let determinant = edge1.dot(p);
+		let culled = match culling {
+			Culling::None => determinant == 0.0,
+			Culling::Back => determinant <= 0.0,
+			Culling::Front => determinant >= 0.0,
+		};
+		if culled || determinant.is_nan() {
+			return None;
+		}
+
+		let inverse = 1.0 / determinant;
+		let offset = self.origin - triangle.a;
+		let u = offset.dot(p) * inverse;
+		if !(0.0..=1.0).contains(&u) {
+			return None;
+		}
+		let q = offset.cross(edge1);
+		let v = self.direction.dot(q) * inverse;
+		if v < 0.0 || u + v > 1.0 {
+			return None;
+		}
+		let distance = edge2.dot(q) * inverse;
+		(distance >= 0.0).then_some(RayHit { distance, u, v })
+	}
+
+	/// Distance to where the ray enters `aabb`, zero when the origin is inside.
+	pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+		slab(self.origin, Vector3::ONE / self.direction, aabb.min, aabb.max, f32::INFINITY)
+	}
+}
+
+/// Slab test against the box from `min` to `max` for a ray given by its origin and reciprocal
+/// direction, `None` unless it enters the box before `max_distance`.
+#[inline(always)]
+pub(crate) fn slab(origin: Vector3, inverse: Vector3, min: Vector3, max: Vector3, max_distance: f32) -> Option<f32> {
+	let near = (min - origin) * inverse;
+	let far = (max - origin) * inverse;
+	// A ray parallel to an axis that starts on one of its planes gives 0 * inf = NaN there, that
+	// axis then doesn't bound it
+	let or_unbounded = |v: Vector3, unbounded: f32| {
+		let axis = |t: f32| if t.is_nan() { unbounded } else { t };
+		Vector3::new(axis(v.x), axis(v.y), axis(v.z))
+	};
+	let entry = or_unbounded(near, f32::NEG_INFINITY).min(or_unbounded(far, f32::NEG_INFINITY)).max_element();
+	let exit = or_unbounded(far, f32::INFINITY).max(or_unbounded(near, f32::INFINITY)).min_element();
+	let entry = libm::fmaxf(entry, 0.0);
+	(entry <= exit && entry <= max_distance).then_some(entry)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::collision::{Culling, Ray, Triangle};
+	use crate::{Aabb, Vector3};
+
+	#[test]
+	fn triangles() {
+		// Counter-clockwise seen from above
+		let triangle = Triangle::new(Vector3::ZERO, Vector3::new(0.0, 0.0, 4.0), Vector3::new(4.0, 0.0, 0.0));
+		let down = Ray::new(Vector3::new(1.0, 5.0, 2.0), Vector3::new(0.0, -2.0, 0.0));
+		let hit = down.intersect_triangle(&triangle, Culling::Back).unwrap();
+		assert_eq!((hit.distance, hit.u, hit.v), (2.5, 0.5, 0.25));
+		assert_eq!(down.at(hit.distance), Vector3::new(1.0, 0.0, 2.0));
+		assert_eq!(down.intersect_triangle(&triangle, Culling::Front), None);
+
+		let up = Ray::new(Vector3::new(1.0, -1.0, 2.0), Vector3::UP);
+		assert_eq!(up.intersect_triangle(&triangle, Culling::Back), None);
+		assert_eq!(up.intersect_triangle(&triangle, Culling::Front).unwrap().distance, 1.0);
+		assert_eq!(up.intersect_triangle(&triangle, Culling::None).unwrap().distance, 1.0);
+
+		// Behind the origin, outside the edges and parallel
+		assert_eq!(Ray::new(Vector3::new(1.0, 5.0, 2.0), Vector3::UP).intersect_triangle(&triangle, Culling::None), None);
+		assert_eq!(Ray::new(Vector3::new(3.0, 5.0, 3.0), Vector3::DOWN).intersect_triangle(&triangle, Culling::None), None);
+		assert_eq!(Ray::new(Vector3::new(-1.0, 0.0, 1.0), Vector3::RIGHT).intersect_triangle(&triangle, Culling::None), None);
+	}
+
+	#[test]
+	fn boxes() {
+		let bounds = Aabb::new(Vector3::new(2.0, -1.0, -1.0), Vector3::new(4.0, 1.0, 1.0));
+		assert_eq!(Ray::new(Vector3::ZERO, Vector3::new(2.0, 0.0, 0.0)).intersect_aabb(&bounds), Some(1.0));
+		assert_eq!(Ray::new(Vector3::new(3.0, 0.0, 0.0), Vector3::UP).intersect_aabb(&bounds), Some(0.0));
+		assert_eq!(Ray::new(Vector3::ZERO, Vector3::new(1.0, 1.0, 0.0)).intersect_aabb(&bounds), None);
+		assert_eq!(Ray::new(Vector3::new(5.0, 0.0, 0.0), Vector3::RIGHT).intersect_aabb(&bounds), None);
+		assert_eq!(Ray::new(Vector3::new(0.0, 0.5, 0.0), Vector3::RIGHT).intersect_aabb(&bounds), Some(2.0));
+
+		// Parallel to a face and starting on its plane
+		assert_eq!(Ray::new(Vector3::new(2.0, 0.0, 0.0), Vector3::UP).intersect_aabb(&bounds), Some(0.0));
+		assert_eq!(Ray::new(Vector3::new(4.0, -3.0, 0.0), Vector3::UP).intersect_aabb(&bounds), Some(2.0));
+		assert_eq!(Ray::new(Vector3::new(0.0, 1.0, 1.0), Vector3::RIGHT).intersect_aabb(&bounds), Some(2.0));
+		assert_eq!(Ray::new(Vector3::new(2.0, -3.0, 1.5), Vector3::UP).intersect_aabb(&bounds), None);
+
+		// Flat along x
+		let flat = Aabb::new(Vector3::new(2.0, -1.0, -1.0), Vector3::new(2.0, 1.0, 1.0));
+		assert_eq!(Ray::new(Vector3::new(2.0, -3.0, 0.0), Vector3::UP).intersect_aabb(&flat), Some(2.0));
+		assert_eq!(Ray::new(Vector3::ZERO, Vector3::RIGHT).intersect_aabb(&flat), Some(2.0));
+	}
+}
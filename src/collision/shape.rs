@@ -0,0 +1,312 @@
+use crate::{Aabb, Quaternion, Transform, Vector3};
+use libm::{fabsf, fmaxf};
+
+/// A convex shape described by its support function, which is all GJK and EPA need.
+pub trait Support {
+	/// The point of the shape furthest along `direction`, which need not be normalized.
+	/// A zero `direction` may return any point of the shape.
+	fn support(&self, direction: Vector3) -> Vector3;
+
+	/// How far the shape is rounded, it is every point within `margin` of its core. Queries run
+	/// on the cores and add the margins back, which keeps them exact on spheres and capsules
+	/// where iterating on the curved surface would only approach the answer.
+	fn margin(&self) -> f32 {
+		0.0
+	}
+
+	/// The support function of the shape before rounding by [`Support::margin`].
+	fn core_support(&self, direction: Vector3) -> Vector3 {
+		self.support(direction)
+	}
+
+	/// This shape placed by `transform`.
+	fn transformed(self, transform: Transform) -> Transformed<Self>
+	where
+		Self: Sized,
+	{
+		Transformed { shape: self, transform }
+	}
+
+	/// The Minkowski sum of this shape and `other`, such as a box with rounded corners from a
+	/// box and a sphere, or the volume swept by a shape from a segment.
+	fn sum<S: Support>(self, other: S) -> Sum<Self, S>
+	where
+		Self: Sized,
+	{
+		Sum(self, other)
+	}
+}
+
+impl<S: Support + ?Sized> Support for &S {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		(**self).support(direction)
+	}
+
+	fn margin(&self) -> f32 {
+		(**self).margin()
+	}
+
+	fn core_support(&self, direction: Vector3) -> Vector3 {
+		(**self).core_support(direction)
+	}
+}
+
+/// The core of a shape as a shape of its own.
+pub(crate) struct Core<'a, S: ?Sized>(pub &'a S);
+
+impl<S: Support + ?Sized> Support for Core<'_, S> {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		self.0.core_support(direction)
+	}
+}
+
+/// A single point.
+impl Support for Vector3 {
+	fn support(&self, _direction: Vector3) -> Vector3 {
+		*self
+	}
+}
+
+/// The convex hull of the points. Panics if the slice is empty.
+impl Support for [Vector3] {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		let mut best = self[0];
+		let mut best_distance = best.dot(direction);
+		for point in &self[1..] {
+			let distance = point.dot(direction);
+			if distance > best_distance {
+				best = *point;
+				best_distance = distance;
+			}
+		}
+		best
+	}
+}
+
+impl<const N: usize> Support for [Vector3; N] {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		self.as_slice().support(direction)
+	}
+}
+
+impl Support for Aabb {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		Vector3::new(
+			if direction.x < 0.0 { self.min.x } else { self.max.x },
+			if direction.y < 0.0 { self.min.y } else { self.max.y },
+			if direction.z < 0.0 { self.min.z } else { self.max.z },
+		)
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere {
+	pub center: Vector3,
+	pub radius: f32,
+}
+
+impl Sphere {
+	pub const fn new(center: Vector3, radius: f32) -> Self {
+		Sphere { center, radius }
+	}
+}
+
+impl Support for Sphere {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		self.center + unit(direction) * self.radius
+	}
+
+	fn margin(&self) -> f32 {
+		self.radius
+	}
+
+	fn core_support(&self, _direction: Vector3) -> Vector3 {
+		self.center
+	}
+}
+
+/// An oriented box, `rotation` turns the box's local axes into world space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Obb {
+	pub center: Vector3,
+	pub half_extents: Vector3,
+	pub rotation: Quaternion,
+}
+
+impl Obb {
+	pub const fn new(center: Vector3, half_extents: Vector3, rotation: Quaternion) -> Self {
+		Obb { center, half_extents, rotation }
+	}
+
+	/// The point of the box closest to `point`, which is `point` itself when inside.
+	pub fn closest_point(&self, point: Vector3) -> Vector3 {
+		let local = self.rotation.conjugate().rotate(point - self.center);
+		self.center + self.rotation.rotate(local.clamp(-self.half_extents, self.half_extents))
+	}
+
+	/// Zero when `point` is inside.
+	pub fn distance_squared(&self, point: Vector3) -> f32 {
+		let offset = point - self.closest_point(point);
+		offset.dot(offset)
+	}
+
+	pub fn distance(&self, point: Vector3) -> f32 {
+		libm::sqrtf(self.distance_squared(point))
+	}
+}
+
+impl From<Aabb> for Obb {
+	fn from(value: Aabb) -> Self {
+		Obb { center: value.center(), half_extents: value.half_extents(), rotation: Quaternion::IDENTITY }
+	}
+}
+
+impl Support for Obb {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		let local = self.rotation.conjugate().rotate(direction);
+		let corner = self.half_extents.copysign(local);
+		self.center + self.rotation.rotate(corner)
+	}
+}
+
+/// Every point within `radius` of the segment from `start` to `end`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capsule {
+	pub start: Vector3,
+	pub end: Vector3,
+	pub radius: f32,
+}
+
+impl Capsule {
+	pub const fn new(start: Vector3, end: Vector3, radius: f32) -> Self {
+		Capsule { start, end, radius }
+	}
+}
+
+impl Support for Capsule {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		self.core_support(direction) + unit(direction) * self.radius
+	}
+
+	fn margin(&self) -> f32 {
+		self.radius
+	}
+
+	fn core_support(&self, direction: Vector3) -> Vector3 {
+		if (self.end - self.start).dot(direction) > 0.0 { self.end } else { self.start }
+	}
+}
+
+/// A shape placed by a [`Transform`], see [`Support::transformed`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transformed<S> {
+	pub shape: S,
+	pub transform: Transform,
+}
+
+impl<S: Support> Transformed<S> {
+	/// The transpose of the linear part maps a direction into the shape's space.
+	fn local(&self, direction: Vector3) -> Vector3 {
+		self.transform.rotation.conjugate().rotate(direction) * self.transform.scale
+	}
+
+	/// Only a uniform scale keeps a rounded shape rounded.
+	fn uniform(&self) -> bool {
+		let scale = self.transform.scale.abs();
+		scale.x == scale.y && scale.y == scale.z
+	}
+}
+
+impl<S: Support> Support for Transformed<S> {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		self.transform.transform_point(self.shape.support(self.local(direction)))
+	}
+
+	fn margin(&self) -> f32 {
+		if self.uniform() { self.shape.margin() * fabsf(self.transform.scale.x) } else { 0.0 }
+	}
+
+	fn core_support(&self, direction: Vector3) -> Vector3 {
+		if self.uniform() {
+			self.transform.transform_point(self.shape.core_support(self.local(direction)))
+		} else {
+			self.support(direction)
+		}
+	}
+}
+
+/// The Minkowski sum of two shapes, see [`Support::sum`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sum<A, B>(pub A, pub B);
+
+impl<A: Support, B: Support> Support for Sum<A, B> {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		self.0.support(direction) + self.1.support(direction)
+	}
+
+	fn margin(&self) -> f32 {
+		self.0.margin() + self.1.margin()
+	}
+
+	fn core_support(&self, direction: Vector3) -> Vector3 {
+		self.0.core_support(direction) + self.1.core_support(direction)
+	}
+}
+
+/// `direction` normalized, or zero when it has no usable length.
+fn unit(direction: Vector3) -> Vector3 {
+	let magnitude = direction.magnitude();
+	if magnitude > f32::MIN_POSITIVE && magnitude.is_finite() {
+		direction / magnitude
+	} else {
+		Vector3::ZERO
+	}
+}
+
+/// Largest absolute coordinate, a cheap measure of how big the numbers in a query are.
+pub(crate) fn extent(point: Vector3) -> f32 {
+	fmaxf(fmaxf(fabsf(point.x), fabsf(point.y)), fabsf(point.z))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::collision::{Capsule, Obb, Sphere, Support};
+	use crate::{Aabb, Quaternion, Transform, Vector3};
+
+	#[test]
+	fn support_points() {
+		let sphere = Sphere::new(Vector3::new(1.0, 0.0, 0.0), 2.0);
+		assert_eq!(sphere.support(Vector3::new(0.0, 5.0, 0.0)), Vector3::new(1.0, 2.0, 0.0));
+		assert_eq!(sphere.support(Vector3::ZERO), sphere.center);
+
+		let capsule = Capsule::new(Vector3::ZERO, Vector3::new(0.0, 4.0, 0.0), 1.0);
+		assert_eq!(capsule.support(Vector3::new(1.0, 1.0, 0.0)).y, 4.0 + core::f32::consts::FRAC_1_SQRT_2);
+		assert_eq!(capsule.support(Vector3::DOWN), Vector3::new(0.0, -1.0, 0.0));
+
+		let turned = Obb::new(Vector3::ZERO, Vector3::new(2.0, 1.0, 1.0), Quaternion::from_axis_angle_degrees(Vector3::FORWARD, 90.0));
+		let corner = turned.support(Vector3::new(1.0, 1.0, 1.0));
+		assert!((corner - Vector3::new(1.0, 2.0, 1.0)).magnitude() < 1e-5);
+		let closest = turned.closest_point(Vector3::new(3.0, 3.0, 0.5));
+		assert!((closest - Vector3::new(1.0, 2.0, 0.5)).magnitude() < 1e-5);
+		assert!((turned.distance(Vector3::new(0.0, 5.0, 0.0)) - 3.0).abs() < 1e-5);
+
+		let points = [Vector3::ZERO, Vector3::new(1.0, 3.0, 0.0), Vector3::new(-2.0, 1.0, 0.0)];
+		assert_eq!(points.support(Vector3::UP), points[1]);
+		assert_eq!(points[..].support(Vector3::LEFT), points[2]);
+
+		let unit = Aabb::new(Vector3::splat(-1.0), Vector3::ONE);
+		let placed = unit.transformed(Transform::new(Vector3::new(5.0, 0.0, 0.0), Quaternion::IDENTITY, Vector3::new(2.0, 1.0, 1.0)));
+		assert_eq!(placed.support(Vector3::new(1.0, -1.0, 0.5)), Vector3::new(7.0, -1.0, 1.0));
+
+		let rounded = unit.sum(Sphere::new(Vector3::ZERO, 0.5));
+		assert_eq!(rounded.support(Vector3::RIGHT), Vector3::new(1.5, 1.0, 1.0));
+		assert_eq!(rounded.core_support(Vector3::RIGHT), Vector3::ONE);
+		assert_eq!(rounded.margin(), 0.5);
+
+		// Uniform scale keeps the margin, any other turns it into part of the core
+		let grown = capsule.transformed(Transform::new(Vector3::ZERO, Quaternion::IDENTITY, Vector3::splat(-2.0)));
+		assert_eq!((grown.margin(), grown.core_support(Vector3::DOWN)), (2.0, Vector3::new(0.0, -8.0, 0.0)));
+		let squashed = capsule.transformed(Transform::new(Vector3::ZERO, Quaternion::IDENTITY, Vector3::new(1.0, 0.5, 1.0)));
+		assert_eq!((squashed.margin(), squashed.core_support(Vector3::DOWN)), (0.0, Vector3::new(0.0, -0.5, 0.0)));
+	}
+}
@@ -0,0 +1,79 @@
+//! Bit-reproducible floating point, enabled by the `deterministic` feature.
+//!
+//! Rust never contracts `a * b + c` into a fused multiply-add or reassociates float arithmetic,
+//! so every operation in this crate already rounds in the order it is written. What can still
+//! differ between platforms is handled here:
+//!
+//! * libm is built with `force-soft-floats`, so transcendental functions, square roots and
+//!   min/max always run the same portable code instead of architecture specific routines.
+//! * 32-bit x86 without SSE2 is rejected, x87 arithmetic rounds through 80-bit registers.
+//!
+//! Explicit fused operations such as [`Vector3::mul_add`](crate::Vector3::mul_add) stay fused,
+//! libm's `fmaf` is correctly rounded and so gives the same bits everywhere. The golden tests
+//! below lock in the bit patterns of the operations replays depend on.
+
+#[cfg(all(target_arch = "x86", not(target_feature = "sse2")))]
+compile_error!("the `deterministic` feature requires SSE2, x87 arithmetic rounds through 80-bit registers");
+
+#[cfg(test)]
+mod tests {
+	use crate::{srgb_to_linear, Matrix4, Quaternion, Vector3};
+
+	fn assert_bits(values: &[f32], golden: &[u32]) {
+		for (i, (value, golden)) in values.iter().zip(golden).enumerate() {
+			assert_eq!(value.to_bits(), *golden, "value {i} is {value}, expected {}", f32::from_bits(*golden));
+		}
+		assert_eq!(values.len(), golden.len());
+	}
+
+	fn matrix() -> Matrix4 {
+		let rotation = Quaternion::from_euler_degrees(Vector3::new(12.5, -40.0, 73.0));
+		Matrix4::from_perspective_radians(1.2, 16.0 / 9.0, 0.1, 500.0)
+			* Matrix4::from_rotation_translation_scale(rotation, Vector3::new(3.0, -7.25, 19.0), Vector3::new(1.5, 0.75, 2.0))
+	}
+
+	#[test]
+	fn invert() {
+		assert_bits(&matrix().invert().to_cols_array(), &[
+			0x3e39f5a3, 0xbfca3d65, 0x3c6a310a, 0xb36ca1e6,
+			0x3eab119b, 0x3e0e5964, 0xbe686105, 0xb3eca1e6,
+			0x41c9ed00, 0xc0bb2d13, 0x423edcd9, 0xc09ff7d4,
+			0xc1cd6f4d, 0x40b42d3f, 0xc2406f50, 0x40a00837,
+		]);
+
+		let (r, t, s) = matrix().invert().invert().to_rotation_translation_scale();
+		assert_bits(&[r.x, r.y, r.z, r.w], &[0xbf217fb7, 0x3f35e4e2, 0xbeb1dae1, 0xbe31d382]);
+		assert_bits(&[t.x, t.y, t.z], &[0x401ddd27, 0xc1298eeb, 0xc199a982]);
+		assert_bits(&[s.x, s.y, s.z], &[0xbff268df, 0x3f22ca20, 0x401ccaf5]);
+	}
+
+	#[test]
+	fn rotations() {
+		let q = Quaternion::from_euler_degrees(Vector3::new(30.0, 45.0, -60.0));
+		assert_bits(&[q.x, q.y, q.z, q.w], &[0x3ec8a79e, 0x3e4d602a, 0xbf082f8f, 0x3f392b54]);
+
+		let euler = Quaternion::from_euler_degrees(Vector3::new(-170.0, 20.0, 100.0)).to_euler();
+		assert_bits(&[euler.x, euler.y, euler.z], &[0xc329ffff, 0x41a00000, 0x42c7ffff]);
+
+		let q = Quaternion::from_axis_angle_degrees(Vector3::new(0.48, -0.6, 0.64), 73.0);
+		assert_bits(&[q.x, q.y, q.z, q.w], &[0x3e922f03, 0xbeb6bac4, 0x3ec2e959, 0x3f4dc991]);
+	}
+
+	#[test]
+	fn vectors() {
+		let mut v = Vector3::new(3.0, -4.5, 0.1);
+		v.normalize();
+		assert_bits(&[v.x, v.y, v.z], &[0x3f0dfa9f, 0xbf54f7ee, 0x3c9771bb]);
+		assert_bits(&[srgb_to_linear(0.5)], &[0x3e5b2d9a]);
+
+		// The batch path must round exactly like the single point one, tail included
+		let golden = [0x3fb66edf, 0xc035add4, 0xc17ee910];
+		let p = matrix().transform_point(Vector3::new(1.0, 2.0, -3.0));
+		assert_bits(&[p.x, p.y, p.z], &golden);
+		let mut points = [Vector3::new(1.0, 2.0, -3.0); 5];
+		matrix().transform_points_in_place(&mut points);
+		for p in points {
+			assert_bits(&[p.x, p.y, p.z], &golden);
+		}
+	}
+}
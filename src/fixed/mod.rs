@@ -0,0 +1,30 @@
+//! Fixed-point scalars, vectors and quaternions for simulations that must match bit for bit
+//! across compilers and CPUs, such as lockstep networking.
+//!
+//! [`Fixed32`] is Q16.16 and [`Fixed64`] is Q32.32. Every operation is integer arithmetic:
+//! square roots use an integer square root and sine and cosine come from a table built at
+//! compile time, so results never depend on the platform's float behaviour. Arithmetic
+//! overflows the way the underlying integer does, panicking in debug builds and wrapping in
+//! release builds.
+//!
+//! The vector and quaternion types default to [`Fixed32`] and convert to and from their float
+//! counterparts with `From`, which is only deterministic in the fixed to float direction:
+//!
+//! ```
+//! use maths::{Fixed32, FixedQuaternion, FixedVector3, Vector3};
+//!
+//! let turn = FixedQuaternion::from_axis_angle(FixedVector3::new(Fixed32::ZERO, Fixed32::ONE, Fixed32::ZERO), Fixed32::from_int(90));
+//! let position = turn.rotate(FixedVector3::new(Fixed32::ONE, Fixed32::ZERO, Fixed32::ZERO));
+//! let rendered: Vector3 = position.into();
+//! assert!((rendered - Vector3::new(0.0, 0.0, -1.0)).magnitude() < 1e-4);
+//! ```
+
+mod quaternion;
+mod scalar;
+mod vector2;
+mod vector3;
+
+pub use quaternion::FixedQuaternion;
+pub use scalar::{Fixed32, Fixed64, FixedPoint};
+pub use vector2::FixedVector2;
+pub use vector3::FixedVector3;
@@ -0,0 +1,112 @@
+use crate::fixed::{Fixed32, FixedPoint, FixedVector3};
+use crate::Quaternion;
+use core::ops::{Mul, MulAssign};
+
+/// A fixed-point rotation, the deterministic counterpart of [`Quaternion`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedQuaternion<T = Fixed32> {
+	pub x: T,
+	pub y: T,
+	pub z: T,
+	pub w: T,
+}
+
+impl<T: FixedPoint> FixedQuaternion<T> {
+	pub const IDENTITY: Self = FixedQuaternion { x: T::ZERO, y: T::ZERO, z: T::ZERO, w: T::ONE };
+
+	pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+		FixedQuaternion { x, y, z, w }
+	}
+
+	/// Rotation of `angle` degrees around `axis`, which must be normalized.
+	pub fn from_axis_angle(axis: FixedVector3<T>, angle: T) -> Self {
+		let (s, c) = (angle / (T::ONE + T::ONE)).sin_cos_degrees();
+		FixedQuaternion { x: axis.x * s, y: axis.y * s, z: axis.z * s, w: c }
+	}
+
+	/// Rotates `vector`, `self` should be normalized.
+	pub fn rotate(&self, vector: FixedVector3<T>) -> FixedVector3<T> {
+		let axis = FixedVector3::new(self.x, self.y, self.z);
+		let t = axis.cross(vector) * (T::ONE + T::ONE);
+		vector + (t * self.w) + axis.cross(t)
+	}
+
+	/// Saturates at the scalar's `MAX`.
+	pub fn magnitude(&self) -> T {
+		T::hypot(&[self.x, self.y, self.z, self.w])
+	}
+
+	/// Panics if `self` is zero.
+	pub fn normalize(&mut self) {
+		let mut components = [self.x, self.y, self.z, self.w];
+		T::normalize(&mut components);
+		[self.x, self.y, self.z, self.w] = components;
+	}
+}
+
+/// Applies `rhs` first, then `self`.
+impl<T: FixedPoint> Mul<FixedQuaternion<T>> for FixedQuaternion<T> {
+	type Output = FixedQuaternion<T>;
+
+	fn mul(self, rhs: FixedQuaternion<T>) -> Self::Output {
+		FixedQuaternion {
+			x: self.x * rhs.w + self.w * rhs.x + self.y * rhs.z - self.z * rhs.y,
+			y: self.y * rhs.w + self.w * rhs.y + self.z * rhs.x - self.x * rhs.z,
+			z: self.z * rhs.w + self.w * rhs.z + self.x * rhs.y - self.y * rhs.x,
+			w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z
+		}
+	}
+}
+
+impl<T: FixedPoint> Mul<FixedVector3<T>> for FixedQuaternion<T> {
+	type Output = FixedVector3<T>;
+
+	fn mul(self, rhs: FixedVector3<T>) -> Self::Output {
+		self.rotate(rhs)
+	}
+}
+
+impl<T: FixedPoint> MulAssign<FixedQuaternion<T>> for FixedQuaternion<T> {
+	fn mul_assign(&mut self, rhs: FixedQuaternion<T>) {
+		*self = *self * rhs;
+	}
+}
+
+impl<T: FixedPoint> From<Quaternion> for FixedQuaternion<T> {
+	fn from(value: Quaternion) -> Self {
+		FixedQuaternion { x: T::from_f32(value.x), y: T::from_f32(value.y), z: T::from_f32(value.z), w: T::from_f32(value.w) }
+	}
+}
+
+impl<T: FixedPoint> From<FixedQuaternion<T>> for Quaternion {
+	fn from(value: FixedQuaternion<T>) -> Self {
+		Quaternion { x: value.x.to_f32(), y: value.y.to_f32(), z: value.z.to_f32(), w: value.w.to_f32() }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Fixed32, Fixed64, FixedQuaternion, FixedVector3, Quaternion, Vector3};
+
+	#[test]
+	fn matches_float() {
+		let axis = Vector3::new(2.0, -1.0, 0.5) / Vector3::new(2.0, -1.0, 0.5).magnitude();
+		let point = Vector3::new(3.0, -7.0, 1.5);
+		for degrees in [-270, -90, -33, 0, 45, 120, 180, 300] {
+			let expected = Quaternion::from_axis_angle_degrees(axis, degrees as f32);
+			let rotation = FixedQuaternion::<Fixed32>::from_axis_angle(axis.into(), Fixed32::from_int(degrees));
+			let rotated = Vector3::from(rotation * FixedVector3::from(point));
+			assert!((rotated - expected * point).magnitude() < 1e-3);
+
+			let composed = Quaternion::from(rotation * rotation);
+			let expected = expected * expected;
+			assert!((composed.x - expected.x).abs() < 1e-4 && (composed.w - expected.w).abs() < 1e-4);
+		}
+
+		let mut scaled = FixedQuaternion::<Fixed64>::new(Fixed64::ZERO, Fixed64::from_int(3), Fixed64::ZERO, Fixed64::from_int(4));
+		scaled.normalize();
+		assert_eq!(Quaternion::from(scaled), Quaternion::new(0.0, 0.6, 0.0, 0.8));
+		assert_eq!(FixedQuaternion::IDENTITY * scaled, scaled);
+	}
+}
@@ -0,0 +1,352 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Operations shared by [`Fixed32`] and [`Fixed64`], the scalar of the fixed-point vectors.
+/// This trait is sealed.
+pub trait FixedPoint:
+	Copy + Ord + fmt::Debug
+	+ Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+	+ AddAssign + SubAssign + MulAssign + DivAssign
+	+ private::Sealed
+{
+	const ZERO: Self;
+	const ONE: Self;
+
+	fn from_f32(value: f32) -> Self;
+	fn to_f32(self) -> f32;
+	fn sqrt(self) -> Self;
+	fn sin_cos_degrees(self) -> (Self, Self);
+}
+
+pub(crate) mod private {
+	pub trait Sealed: Sized {
+		/// Square root of the sum of squares, accumulated at double width so it cannot overflow
+		/// before the root is taken, saturating at `MAX`.
+		fn hypot(components: &[Self]) -> Self;
+
+		/// Scales `components` to unit length, dividing by the largest first so the magnitude
+		/// stays in range. Panics if they are all zero.
+		fn normalize(components: &mut [Self]);
+	}
+}
+
+/// `sin(i * 90 / 256)` degrees in Q32.32 for `i` in `0..=256`.
+const SINE: [i64; 257] = sine_table();
+
+const fn sine_table() -> [i64; 257] {
+	let mut table = [0; 257];
+	let mut i = 0;
+	while i < table.len() {
+		let x = i as f64 * (core::f64::consts::FRAC_PI_2 / 256.0);
+		// Taylor series, the 15th term is far below f64 precision for x <= pi/2
+		let mut term = x;
+		let mut sum = x;
+		let mut n = 1;
+		while n < 15 {
+			term *= -(x * x) / ((2 * n) as f64 * (2 * n + 1) as f64);
+			sum += term;
+			n += 1;
+		}
+		table[i] = (sum * 4294967296.0 + 0.5) as i64;
+		i += 1;
+	}
+	table
+}
+
+/// Sine of a whole number of 2^-32 turns in Q32.32, interpolated between table entries.
+fn sin_turns(turns: u32) -> i64 {
+	let quadrant = turns >> 30;
+	let mut position = turns & 0x3fff_ffff;
+	if quadrant & 1 == 1 {
+		position = (1 << 30) - position;
+	}
+
+	let index = (position >> 22) as usize;
+	let fraction = (position & 0x3f_ffff) as i64;
+	let a = SINE[index];
+	let b = SINE[(index + 1).min(256)];
+	let value = a + (((b - a) * fraction) >> 22);
+	if quadrant >= 2 { -value } else { value }
+}
+
+/// 2^40 turns per radian and per degree.
+const RADIAN_TURNS: i64 = (1099511627776.0 / core::f64::consts::TAU + 0.5) as i64;
+const DEGREE_TURNS: i64 = (1099511627776.0 / 360.0 + 0.5) as i64;
+
+macro_rules! impl_fixed {
+	($ty:ident, $bits:ty, $wide:ty, $unsigned_wide:ty, $fraction:literal, $doc:literal) => {
+		#[doc = $doc]
+		#[repr(transparent)]
+		#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		pub struct $ty($bits);
+
+		impl $ty {
+			pub const FRACTIONAL_BITS: u32 = $fraction;
+			pub const ZERO: $ty = $ty(0);
+			pub const ONE: $ty = $ty(1 << $fraction);
+			pub const HALF: $ty = $ty(1 << ($fraction - 1));
+			pub const MIN: $ty = $ty(<$bits>::MIN);
+			pub const MAX: $ty = $ty(<$bits>::MAX);
+			/// The smallest positive value.
+			pub const EPSILON: $ty = $ty(1);
+			pub const PI: $ty = $ty::from_f64(core::f64::consts::PI);
+			pub const TAU: $ty = $ty::from_f64(core::f64::consts::TAU);
+			pub const FRAC_PI_2: $ty = $ty::from_f64(core::f64::consts::FRAC_PI_2);
+
+			pub const fn from_bits(bits: $bits) -> Self {
+				$ty(bits)
+			}
+
+			pub const fn to_bits(self) -> $bits {
+				self.0
+			}
+
+			pub const fn from_int(value: $bits) -> Self {
+				$ty(value << $fraction)
+			}
+
+			/// Rounds towards negative infinity.
+			pub const fn to_int(self) -> $bits {
+				self.0 >> $fraction
+			}
+
+			/// Rounds to the nearest representable value, saturating at [`Self::MIN`] and [`Self::MAX`].
+			pub const fn from_f32(value: f32) -> Self {
+				$ty::from_f64(value as f64)
+			}
+
+			/// Rounds to the nearest representable value, saturating at [`Self::MIN`] and [`Self::MAX`].
+			pub const fn from_f64(value: f64) -> Self {
+				let scaled = value * (1u64 << $fraction) as f64;
+				$ty((if scaled < 0.0 { scaled - 0.5 } else { scaled + 0.5 }) as $bits)
+			}
+
+			pub const fn to_f32(self) -> f32 {
+				self.to_f64() as f32
+			}
+
+			pub const fn to_f64(self) -> f64 {
+				self.0 as f64 / (1u64 << $fraction) as f64
+			}
+
+			pub const fn abs(self) -> Self {
+				$ty(self.0.abs())
+			}
+
+			/// Same as the `+` operator, usable in constant expressions.
+			#[allow(clippy::should_implement_trait)]
+			pub const fn add(self, rhs: $ty) -> $ty {
+				$ty(self.0 + rhs.0)
+			}
+
+			/// Same as the `-` operator, usable in constant expressions.
+			#[allow(clippy::should_implement_trait)]
+			pub const fn sub(self, rhs: $ty) -> $ty {
+				$ty(self.0 - rhs.0)
+			}
+
+			/// Same as the `*` operator, usable in constant expressions.
+			#[allow(clippy::should_implement_trait)]
+			pub const fn mul(self, rhs: $ty) -> $ty {
+				$ty::narrow((self.0 as $wide * rhs.0 as $wide) >> $fraction)
+			}
+
+			/// Panics if `self` is negative.
+			pub fn sqrt(self) -> Self {
+				assert!(self.0 >= 0, "square root of a negative number");
+				$ty(((self.0 as $unsigned_wide) << $fraction).isqrt() as $bits)
+			}
+
+			/// Sine of an angle in radians.
+			pub fn sin(self) -> Self {
+				$ty::from_q32(sin_turns(self.turns(RADIAN_TURNS)))
+			}
+
+			/// Cosine of an angle in radians.
+			pub fn cos(self) -> Self {
+				$ty::from_q32(sin_turns(self.turns(RADIAN_TURNS).wrapping_add(1 << 30)))
+			}
+
+			/// Sine and cosine of an angle in radians.
+			pub fn sin_cos(self) -> (Self, Self) {
+				$ty::sin_cos_of_turns(self.turns(RADIAN_TURNS))
+			}
+
+			/// Sine and cosine of an angle in degrees.
+			pub fn sin_cos_degrees(self) -> (Self, Self) {
+				$ty::sin_cos_of_turns(self.turns(DEGREE_TURNS))
+			}
+
+			fn sin_cos_of_turns(turns: u32) -> (Self, Self) {
+				($ty::from_q32(sin_turns(turns)), $ty::from_q32(sin_turns(turns.wrapping_add(1 << 30))))
+			}
+
+			/// The angle as a fraction of a turn in 2^-32 steps, wrapped to `[0, 1)`.
+			fn turns(self, turns_per_unit: i64) -> u32 {
+				((self.0 as i128 * turns_per_unit as i128) >> ($fraction + 8)) as u32
+			}
+
+			/// Rounds a Q32.32 value to this format.
+			fn from_q32(value: i64) -> Self {
+				$ty(((value + ((1 << 31) >> $fraction)) >> (32 - $fraction)) as $bits)
+			}
+
+			/// Narrows a double width intermediate, overflowing like the integer arithmetic operators.
+			const fn narrow(value: $wide) -> Self {
+				if cfg!(debug_assertions) && (value < <$bits>::MIN as $wide || value > <$bits>::MAX as $wide) {
+					panic!("fixed point arithmetic overflowed");
+				}
+				$ty(value as $bits)
+			}
+		}
+
+		impl FixedPoint for $ty {
+			const ZERO: $ty = $ty::ZERO;
+			const ONE: $ty = $ty::ONE;
+
+			fn from_f32(value: f32) -> Self {
+				$ty::from_f32(value)
+			}
+
+			fn to_f32(self) -> f32 {
+				$ty::to_f32(self)
+			}
+
+			fn sqrt(self) -> Self {
+				$ty::sqrt(self)
+			}
+
+			fn sin_cos_degrees(self) -> (Self, Self) {
+				$ty::sin_cos_degrees(self)
+			}
+		}
+
+		impl private::Sealed for $ty {
+			fn hypot(components: &[Self]) -> Self {
+				let sum = components.iter().fold(0 as $unsigned_wide, |sum, c| sum.saturating_add((c.0 as $wide * c.0 as $wide) as $unsigned_wide));
+				$ty(sum.isqrt().min(<$bits>::MAX as $unsigned_wide) as $bits)
+			}
+
+			fn normalize(components: &mut [Self]) {
+				let largest = components.iter().map(|c| c.0.unsigned_abs()).max().unwrap_or(0);
+				assert!(largest != 0, "normalizing a zero vector");
+				for c in components.iter_mut() {
+					c.0 = (((c.0 as $wide) << $fraction) / largest as $wide) as $bits;
+				}
+				let magnitude = Self::hypot(components);
+				for c in components.iter_mut() {
+					*c /= magnitude;
+				}
+			}
+		}
+
+		impl fmt::Debug for $ty {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				fmt::Debug::fmt(&self.to_f64(), f)
+			}
+		}
+
+		impl fmt::Display for $ty {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				fmt::Display::fmt(&self.to_f64(), f)
+			}
+		}
+
+		impl Add for $ty {
+			type Output = $ty;
+
+			fn add(self, rhs: $ty) -> Self::Output {
+				$ty::add(self, rhs)
+			}
+		}
+		impl AddAssign for $ty {
+			fn add_assign(&mut self, rhs: $ty) {
+				self.0 += rhs.0;
+			}
+		}
+
+		impl Sub for $ty {
+			type Output = $ty;
+
+			fn sub(self, rhs: $ty) -> Self::Output {
+				$ty::sub(self, rhs)
+			}
+		}
+		impl SubAssign for $ty {
+			fn sub_assign(&mut self, rhs: $ty) {
+				self.0 -= rhs.0;
+			}
+		}
+
+		/// Rounds towards negative infinity.
+		impl Mul for $ty {
+			type Output = $ty;
+
+			fn mul(self, rhs: $ty) -> Self::Output {
+				$ty::mul(self, rhs)
+			}
+		}
+		impl MulAssign for $ty {
+			fn mul_assign(&mut self, rhs: $ty) {
+				*self = *self * rhs;
+			}
+		}
+
+		/// Rounds towards zero, panics if `rhs` is zero.
+		impl Div for $ty {
+			type Output = $ty;
+
+			fn div(self, rhs: $ty) -> Self::Output {
+				$ty::narrow(((self.0 as $wide) << $fraction) / rhs.0 as $wide)
+			}
+		}
+		impl DivAssign for $ty {
+			fn div_assign(&mut self, rhs: $ty) {
+				*self = *self / rhs;
+			}
+		}
+
+		impl Neg for $ty {
+			type Output = $ty;
+
+			fn neg(self) -> Self::Output {
+				$ty(-self.0)
+			}
+		}
+	};
+}
+
+impl_fixed!(Fixed32, i32, i64, u64, 16, "A Q16.16 fixed-point number, covering ±32768 in steps of 2^-16.");
+impl_fixed!(Fixed64, i64, i128, u128, 32, "A Q32.32 fixed-point number, covering ±2^31 in steps of 2^-32.");
+
+#[cfg(test)]
+mod tests {
+	use crate::{Fixed32, Fixed64};
+
+	#[test]
+	fn arithmetic() {
+		let a = Fixed32::from_f32(2.5);
+		let b = Fixed32::from_int(-4);
+		assert_eq!(a + b, Fixed32::from_f32(-1.5));
+		assert_eq!(a * b, Fixed32::from_int(-10));
+		assert_eq!((b / a).to_bits(), -104857);
+		assert_eq!(Fixed32::from_int(9).sqrt(), Fixed32::from_int(3));
+		assert_eq!(Fixed64::from_int(2).sqrt().to_bits(), 6074000999);
+		assert_eq!(Fixed32::from_f32(-1.25).to_int(), -2);
+		assert_eq!(Fixed32::PI.to_bits(), 205887);
+	}
+
+	#[test]
+	fn trigonometry() {
+		for degrees in -720..=720 {
+			let radians = (degrees as f64).to_radians();
+			let (sin, cos) = Fixed32::from_int(degrees).sin_cos_degrees();
+			assert!((sin.to_f64() - libm::sin(radians)).abs() < 2e-5 && (cos.to_f64() - libm::cos(radians)).abs() < 2e-5);
+
+			let angle = Fixed64::from_f64(radians);
+			assert!((angle.sin().to_f64() - libm::sin(radians)).abs() < 1e-5 && (angle.cos().to_f64() - libm::cos(radians)).abs() < 1e-5);
+		}
+		assert_eq!(Fixed32::from_int(90).sin_cos_degrees(), (Fixed32::ONE, Fixed32::ZERO));
+		assert_eq!(Fixed32::PI.sin_cos(), (Fixed32::ZERO, -Fixed32::ONE));
+	}
+}
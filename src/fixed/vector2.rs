@@ -0,0 +1,31 @@
+use crate::fixed::{Fixed32, Fixed64, FixedPoint};
+use crate::vector::impl_vector;
+use crate::Vector2;
+
+/// A fixed-point 2D vector, the deterministic counterpart of [`Vector2`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FixedVector2<T = Fixed32> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T: FixedPoint> FixedVector2<T> {
+	pub const fn new(x: T, y: T) -> Self {
+		FixedVector2 { x, y }
+	}
+}
+
+impl_vector!(FixedVector2<T>, [Fixed32, Fixed64], [x, y]);
+
+impl<T: FixedPoint> From<Vector2> for FixedVector2<T> {
+	fn from(value: Vector2) -> Self {
+		FixedVector2 { x: T::from_f32(value.x), y: T::from_f32(value.y) }
+	}
+}
+
+impl<T: FixedPoint> From<FixedVector2<T>> for Vector2 {
+	fn from(value: FixedVector2<T>) -> Self {
+		Vector2 { x: value.x.to_f32(), y: value.y.to_f32() }
+	}
+}
@@ -0,0 +1,94 @@
+use crate::fixed::{Fixed32, Fixed64, FixedPoint};
+use crate::vector::impl_vector;
+use crate::Vector3;
+
+/// A fixed-point 3D vector, the deterministic counterpart of [`Vector3`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FixedVector3<T = Fixed32> {
+	pub x: T,
+	pub y: T,
+	pub z: T,
+}
+
+impl<T: FixedPoint> FixedVector3<T> {
+	pub const fn new(x: T, y: T, z: T) -> Self {
+		FixedVector3 { x, y, z }
+	}
+
+	pub fn cross(&self, rhs: FixedVector3<T>) -> FixedVector3<T> {
+		FixedVector3 {
+			x: (self.y * rhs.z) - (self.z * rhs.y),
+			y: (self.z * rhs.x) - (self.x * rhs.z),
+			z: (self.x * rhs.y) - (self.y * rhs.x),
+		}
+	}
+}
+
+impl_vector!(FixedVector3<T>, [Fixed32, Fixed64], [x, y, z]);
+
+impl<T: FixedPoint> From<Vector3> for FixedVector3<T> {
+	fn from(value: Vector3) -> Self {
+		FixedVector3 { x: T::from_f32(value.x), y: T::from_f32(value.y), z: T::from_f32(value.z) }
+	}
+}
+
+impl<T: FixedPoint> From<FixedVector3<T>> for Vector3 {
+	fn from(value: FixedVector3<T>) -> Self {
+		Vector3::new(value.x.to_f32(), value.y.to_f32(), value.z.to_f32())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Fixed32, Fixed64, FixedQuaternion, FixedVector2, FixedVector3, Vector2, Vector3};
+
+	#[test]
+	fn arithmetic() {
+		let a: FixedVector3 = Vector3::new(1.5, -2.0, 0.25).into();
+		let b: FixedVector3 = Vector3::new(4.0, 0.5, -8.0).into();
+		assert_eq!(Vector3::from(a + b), Vector3::new(5.5, -1.5, -7.75));
+		assert_eq!(Vector3::from(a * Fixed32::from_int(2) - b), Vector3::new(-1.0, -4.5, 8.5));
+		assert_eq!(a.dot(b), Fixed32::from_int(3));
+		assert_eq!(Vector3::from(a.cross(b)), Vector3::new(15.875, 13.0, 8.75));
+		assert_eq!(-(-a), a);
+
+		// The squares sum past the Q16.16 range, the magnitude does not
+		let far = FixedVector3::splat(Fixed32::from_int(200));
+		assert_eq!(far.magnitude().to_int(), 346);
+
+		let mut direction = FixedVector2::<Fixed64>::from(Vector2::new(3.0, 4.0));
+		assert_eq!(direction.magnitude(), Fixed64::from_int(5));
+		direction.normalize();
+		assert_eq!(Vector2::from(direction), Vector2::new(0.6, 0.8));
+	}
+
+	#[test]
+	fn range_limits() {
+		// Longer than Q16.16 can hold
+		let mut long = FixedVector2::<Fixed32>::from(Vector2::new(30000.0, 30000.0));
+		assert_eq!(long.magnitude(), Fixed32::MAX);
+		long.normalize();
+		let unit = Vector2::from(long);
+		assert!((unit - Vector2::new(0.70710677, 0.70710677)).magnitude() < 1e-4);
+
+		let mut corner = FixedVector3::splat(Fixed32::MIN);
+		assert_eq!(corner.magnitude(), Fixed32::MAX);
+		corner.normalize();
+		assert!((Vector3::from(corner) + Vector3::splat(0.57735026)).magnitude() < 1e-4);
+
+		// Four squares of MIN sum to exactly the unsigned double width range
+		for mut rotation in [FixedQuaternion::new(Fixed32::MIN, Fixed32::MIN, Fixed32::MIN, Fixed32::MIN), FixedQuaternion::new(Fixed32::MAX, Fixed32::MIN, Fixed32::MAX, Fixed32::MIN)] {
+			assert_eq!(rotation.magnitude(), Fixed32::MAX);
+			rotation.normalize();
+			assert!((rotation.x.to_f32().abs() - 0.5).abs() < 1e-4 && (rotation.w.to_f32().abs() - 0.5).abs() < 1e-4);
+		}
+		let rotation = FixedQuaternion::new(Fixed64::MIN, Fixed64::MIN, Fixed64::MIN, Fixed64::MIN);
+		assert_eq!(rotation.magnitude(), Fixed64::MAX);
+
+		// Tiny vectors keep their direction too
+		let mut tiny = FixedVector2::new(Fixed32::EPSILON, Fixed32::EPSILON * Fixed32::from_int(2));
+		tiny.normalize();
+		assert!((Vector2::from(tiny) - Vector2::new(0.4472136, 0.8944272)).magnitude() < 1e-4);
+	}
+}
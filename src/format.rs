@@ -0,0 +1,400 @@
+//! `Display` and `FromStr` for every type.
+//!
+//! Vectors, quaternions and colors print as `(1.5, 2, -3)` and forward the precision flag to
+//! each component, so `{:.3}` prints three decimals. Matrices print their rows, on one line
+//! as `[[1, 0, 0], [0, 1, 0], [0, 0, 1]]` or, with the alternate flag, one aligned row per line.
+//!
+//! Parsing accepts components separated by commas and/or whitespace, optionally wrapped in
+//! parentheses or brackets: `(1.5, 2, -3)`, `[1.5, 2, -3]` and `1.5 2 -3` are all the same
+//! vector. Matrices are read row by row, with or without brackets around each row.
+
+use crate::{HexParseError, IVector2, LinearRgba, Matrix3, Matrix4, Quaternion, Srgba, Vector2, Vector3, Vector4};
+use core::fmt::{self, Display, Write};
+use core::str::FromStr;
+
+/// Error returned when parsing a type from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+	/// The component at this index is not a valid number.
+	InvalidNumber(usize),
+	/// The string held a different number of components than the type has.
+	WrongCount { expected: usize, found: usize },
+	/// Parentheses or brackets are unbalanced, mismatched or nested too deeply.
+	Brackets,
+	/// A `#` prefixed color was not valid hex.
+	Hex(HexParseError),
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseError::InvalidNumber(index) => write!(f, "component {index} is not a valid number"),
+			ParseError::WrongCount { expected, found } => write!(f, "expected {expected} components, found {found}"),
+			ParseError::Brackets => f.write_str("unbalanced or mismatched brackets"),
+			ParseError::Hex(error) => error.fmt(f),
+		}
+	}
+}
+
+impl core::error::Error for ParseError {}
+
+impl From<HexParseError> for ParseError {
+	fn from(error: HexParseError) -> Self {
+		ParseError::Hex(error)
+	}
+}
+
+/// Writes a single component, forwarding the precision flag.
+fn write_component<T: Display>(f: &mut impl Write, precision: Option<usize>, width: usize, value: &T) -> fmt::Result {
+	match precision {
+		Some(precision) => write!(f, "{value:>width$.precision$}"),
+		None => write!(f, "{value:>width$}"),
+	}
+}
+
+fn write_tuple<T: Display>(f: &mut fmt::Formatter<'_>, components: &[T]) -> fmt::Result {
+	let precision = f.precision();
+	f.write_str("(")?;
+	for (index, component) in components.iter().enumerate() {
+		if index > 0 {
+			f.write_str(", ")?;
+		}
+		write_component(f, precision, 0, component)?;
+	}
+	f.write_str(")")
+}
+
+/// Counts the characters written, used to line up matrix columns without allocating.
+struct Width(usize);
+
+impl Write for Width {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.0 += s.chars().count();
+		Ok(())
+	}
+}
+
+fn write_rows<const N: usize>(f: &mut fmt::Formatter<'_>, rows: [[f32; N]; N]) -> fmt::Result {
+	let precision = f.precision();
+	let pretty = f.alternate();
+
+	let mut widths = [0; N];
+	if pretty {
+		for row in &rows {
+			for (width, value) in widths.iter_mut().zip(row) {
+				let mut counter = Width(0);
+				write_component(&mut counter, precision, 0, value)?;
+				*width = (*width).max(counter.0);
+			}
+		}
+	} else {
+		f.write_str("[")?;
+	}
+
+	for (index, row) in rows.iter().enumerate() {
+		if index > 0 {
+			f.write_str(if pretty { "\n" } else { ", " })?;
+		}
+		f.write_str("[")?;
+		for (column, value) in row.iter().enumerate() {
+			if column > 0 {
+				f.write_str(", ")?;
+			}
+			write_component(f, precision, widths[column], value)?;
+		}
+		f.write_str("]")?;
+	}
+
+	if !pretty {
+		f.write_str("]")?;
+	}
+	Ok(())
+}
+
+/// Parses exactly `N` components, allowing brackets up to `max_depth` levels deep.
+fn parse_components<T: FromStr + Copy + Default, const N: usize>(s: &str, max_depth: usize) -> Result<[T; N], ParseError> {
+	let mut closers = ['\0'; 2];
+	let mut depth = 0;
+	for c in s.chars() {
+		match c {
+			'(' | '[' => {
+				if depth == max_depth {
+					return Err(ParseError::Brackets);
+				}
+				closers[depth] = if c == '(' { ')' } else { ']' };
+				depth += 1;
+			}
+			')' | ']' => {
+				if depth == 0 || closers[depth - 1] != c {
+					return Err(ParseError::Brackets);
+				}
+				depth -= 1;
+			}
+			_ => {}
+		}
+	}
+	if depth != 0 {
+		return Err(ParseError::Brackets);
+	}
+
+	let mut components = [T::default(); N];
+	let mut found = 0;
+	let tokens = s.split(|c: char| c == ',' || c == '(' || c == ')' || c == '[' || c == ']' || c.is_whitespace()).filter(|token| !token.is_empty());
+	for token in tokens {
+		if found < N {
+			components[found] = token.parse().map_err(|_| ParseError::InvalidNumber(found))?;
+		}
+		found += 1;
+	}
+	if found != N {
+		return Err(ParseError::WrongCount { expected: N, found });
+	}
+	Ok(components)
+}
+
+impl Display for Vector2 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_tuple(f, &[self.x, self.y])
+	}
+}
+
+impl FromStr for Vector2 {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [x, y] = parse_components(s, 1)?;
+		Ok(Vector2 { x, y })
+	}
+}
+
+impl Display for Vector3 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_tuple(f, &[self.x, self.y, self.z])
+	}
+}
+
+impl FromStr for Vector3 {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [x, y, z] = parse_components(s, 1)?;
+		Ok(Vector3::new(x, y, z))
+	}
+}
+
+impl Display for Vector4 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_tuple(f, &[self.x, self.y, self.z, self.w])
+	}
+}
+
+impl FromStr for Vector4 {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [x, y, z, w] = parse_components(s, 1)?;
+		Ok(Vector4 { x, y, z, w })
+	}
+}
+
+impl Display for IVector2 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_tuple(f, &[self.x, self.y])
+	}
+}
+
+impl FromStr for IVector2 {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [x, y] = parse_components(s, 1)?;
+		Ok(IVector2 { x, y })
+	}
+}
+
+/// Prints the raw components as `(x, y, z, w)`.
+impl Display for Quaternion {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_tuple(f, &[self.x, self.y, self.z, self.w])
+	}
+}
+
+impl FromStr for Quaternion {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [x, y, z, w] = parse_components(s, 1)?;
+		Ok(Quaternion { x, y, z, w })
+	}
+}
+
+impl Display for LinearRgba {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_tuple(f, &[self.r, self.g, self.b, self.a])
+	}
+}
+
+impl FromStr for LinearRgba {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [r, g, b, a] = parse_components(s, 1)?;
+		Ok(LinearRgba { r, g, b, a })
+	}
+}
+
+impl Display for Srgba {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_tuple(f, &[self.r, self.g, self.b, self.a])
+	}
+}
+
+/// Parses four components like the other types, or a `#` prefixed hex color as in [`Srgba::from_hex`].
+impl FromStr for Srgba {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+		if s.starts_with('#') {
+			return Ok(Srgba::from_hex(s)?);
+		}
+		let [r, g, b, a] = parse_components(s, 1)?;
+		Ok(Srgba { r, g, b, a })
+	}
+}
+
+impl Display for Matrix3 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_rows(f, [
+			[self.x.x, self.y.x, self.z.x],
+			[self.x.y, self.y.y, self.z.y],
+			[self.x.z, self.y.z, self.z.z],
+		])
+	}
+}
+
+/// Reads 9 numbers row by row.
+impl FromStr for Matrix3 {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [
+			x0, y0, z0,
+			x1, y1, z1,
+			x2, y2, z2,
+		] = parse_components(s, 2)?;
+		Ok(Matrix3 {
+			x: Vector3::new(x0, x1, x2),
+			y: Vector3::new(y0, y1, y2),
+			z: Vector3::new(z0, z1, z2),
+		})
+	}
+}
+
+impl Display for Matrix4 {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write_rows(f, [
+			[self.m00, self.m10, self.m20, self.m30],
+			[self.m01, self.m11, self.m21, self.m31],
+			[self.m02, self.m12, self.m22, self.m32],
+			[self.m03, self.m13, self.m23, self.m33],
+		])
+	}
+}
+
+/// Reads 16 numbers row by row.
+impl FromStr for Matrix4 {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let [
+			m00, m10, m20, m30,
+			m01, m11, m21, m31,
+			m02, m12, m22, m32,
+			m03, m13, m23, m33,
+		] = parse_components(s, 2)?;
+		Ok(Matrix4 {
+			m00, m01, m02, m03,
+			m10, m11, m12, m13,
+			m20, m21, m22, m23,
+			m30, m31, m32, m33,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{HexParseError, IVector2, Matrix3, Matrix4, ParseError, Quaternion, Srgba, Vector2, Vector3};
+	use core::fmt::{self, Write};
+
+	/// Formats into a fixed buffer since the crate has no allocator.
+	struct Buffer {
+		bytes: [u8; 256],
+		len: usize,
+	}
+
+	impl Buffer {
+		fn format(args: fmt::Arguments) -> Buffer {
+			let mut buffer = Buffer { bytes: [0; 256], len: 0 };
+			buffer.write_fmt(args).unwrap();
+			buffer
+		}
+
+		fn as_str(&self) -> &str {
+			core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+		}
+	}
+
+	impl Write for Buffer {
+		fn write_str(&mut self, s: &str) -> fmt::Result {
+			let end = self.len + s.len();
+			self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+			self.len = end;
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn display() {
+		assert_eq!(Buffer::format(format_args!("{}", Vector3::new(1.5, 2.0, -3.0))).as_str(), "(1.5, 2, -3)");
+		assert_eq!(Buffer::format(format_args!("{:.2}", Vector2 { x: 1.0, y: 0.125 })).as_str(), "(1.00, 0.12)");
+		assert_eq!(Buffer::format(format_args!("{}", IVector2 { x: -4, y: 7 })).as_str(), "(-4, 7)");
+		assert_eq!(Buffer::format(format_args!("{}", Quaternion::IDENTITY)).as_str(), "(0, 0, 0, 1)");
+		assert_eq!(Buffer::format(format_args!("{}", Matrix3::from_translation(Vector2 { x: 4.0, y: -5.0 }))).as_str(), "[[1, 0, 4], [0, 1, -5], [0, 0, 1]]");
+
+		let translation = Matrix4::IDENTITY.translate(Vector3::new(10.0, -2.5, 0.0));
+		assert_eq!(
+			Buffer::format(format_args!("{:#.1}", translation)).as_str(),
+			"[1.0, 0.0, 0.0, 10.0]\n[0.0, 1.0, 0.0, -2.5]\n[0.0, 0.0, 1.0,  0.0]\n[0.0, 0.0, 0.0,  1.0]",
+		);
+	}
+
+	#[test]
+	fn parse() {
+		let expected = Vector3::new(1.5, 2.0, -3.0);
+		assert_eq!("(1.5, 2, -3)".parse(), Ok(expected));
+		assert_eq!("[1.5, 2, -3]".parse(), Ok(expected));
+		assert_eq!("  1.5 2\t-3 ".parse(), Ok(expected));
+		assert_eq!("1.5,2,-3".parse(), Ok(expected));
+		assert_eq!("(1.5, 2)".parse::<Vector3>(), Err(ParseError::WrongCount { expected: 3, found: 2 }));
+		assert_eq!("(1.5, two, 3)".parse::<Vector3>(), Err(ParseError::InvalidNumber(1)));
+		assert_eq!("(1.5, 2, 3]".parse::<Vector3>(), Err(ParseError::Brackets));
+		assert_eq!("((1.5, 2, 3))".parse::<Vector3>(), Err(ParseError::Brackets));
+		assert_eq!("(3, -4)".parse(), Ok(IVector2 { x: 3, y: -4 }));
+		assert_eq!("(3.5, -4)".parse::<IVector2>(), Err(ParseError::InvalidNumber(0)));
+
+		assert_eq!("#ff0000".parse(), Ok(Srgba::RED));
+		assert_eq!("(1, 0, 0, 1)".parse(), Ok(Srgba::RED));
+		assert_eq!("#ff00f".parse::<Srgba>(), Err(ParseError::Hex(HexParseError::InvalidLength)));
+
+		let translation = Matrix4::IDENTITY.translate(Vector3::new(10.0, -2.5, 0.0));
+		let pretty = Buffer::format(format_args!("{:#}", translation));
+		assert_eq!(pretty.as_str().parse(), Ok(translation));
+		let line = Buffer::format(format_args!("{}", translation));
+		assert_eq!(line.as_str().parse(), Ok(translation));
+		assert_eq!("1 0 0 10  0 1 0 -2.5  0 0 1 0  0 0 0 1".parse(), Ok(translation));
+
+		let basis = Matrix3::from_translation(Vector2 { x: 4.0, y: -5.0 });
+		assert_eq!("[[1, 0, 4], [0, 1, -5], [0, 0, 1]]".parse(), Ok(basis));
+	}
+}
@@ -0,0 +1,38 @@
+//! Maps uniform samples onto shapes, shared by the `rand`, `arbitrary` and `proptest` features.
+
+use crate::{Quaternion, Vector3};
+use core::f32::consts::TAU;
+use libm::{cosf, sinf, sqrtf};
+
+/// Unit vector from `z` in `[-1, 1]` and `turn` in `[0, 1)`, uniform over the sphere when both are.
+pub(crate) fn unit_vector3(z: f32, turn: f32) -> Vector3 {
+	let angle = TAU * turn;
+	let r = sqrtf(1.0 - z * z);
+	Vector3::new(r * cosf(angle), r * sinf(angle), z)
+}
+
+/// Shoemake's subgroup algorithm, uniformly distributed rotations when the inputs are uniform in `[0, 1)`.
+pub(crate) fn unit_quaternion(u1: f32, u2: f32, u3: f32) -> Quaternion {
+	let (a, b) = (TAU * u2, TAU * u3);
+	let r1 = sqrtf(1.0 - u1);
+	let r2 = sqrtf(u1);
+	Quaternion { x: r1 * sinf(a), y: r1 * cosf(a), z: r2 * sinf(b), w: r2 * cosf(b) }
+}
+
+/// Column-major values in `[-1, 1]` with the diagonal pushed out to at least 4, the matrix is
+/// strictly diagonally dominant so always invertible and well conditioned.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub(crate) fn invertible_matrix4(mut values: [f32; 16]) -> crate::Matrix4 {
+	for diagonal in [0, 5, 10, 15] {
+		let value = values[diagonal];
+		values[diagonal] = if value < 0.0 { value - 4.0 } else { value + 4.0 };
+	}
+	crate::Matrix4::from_cols_array(values)
+}
+
+/// Scale magnitude in `[0.1, 10]` from `t` in `[0, 1)`, negative when `negative` is set.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub(crate) fn scale_factor(t: f32, negative: bool) -> f32 {
+	let magnitude = libm::powf(10.0, 2.0 * t - 1.0);
+	if negative { -magnitude } else { magnitude }
+}
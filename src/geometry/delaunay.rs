@@ -0,0 +1,711 @@
+use super::predicates::{in_circle, orient};
+use crate::Vector2;
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use libm::{ceil, fabs, floor, sqrt};
+
+/// Marks a half-edge on the boundary, with no triangle on its other side.
+const EMPTY: u32 = u32::MAX;
+
+/// Circumcenter of `a`, `b`, `c` relative to `a`, with the squared radius.
+fn circumcircle(a: Vector2, b: Vector2, c: Vector2) -> ([f64; 2], f64) {
+	let (bx, by) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+	let (cx, cy) = (c.x as f64 - a.x as f64, c.y as f64 - a.y as f64);
+	let (b2, c2) = (bx * bx + by * by, cx * cx + cy * cy);
+	let d = 2.0 * (bx * cy - by * cx);
+	let (x, y) = ((cy * b2 - by * c2) / d, (bx * c2 - cx * b2) / d);
+	([x, y], x * x + y * y)
+}
+
+const fn next(edge: u32) -> u32 {
+	if edge % 3 == 2 { edge - 2 } else { edge + 1 }
+}
+
+const fn prev(edge: u32) -> u32 {
+	if edge.is_multiple_of(3) { edge + 2 } else { edge - 1 }
+}
+
+/// Error returned when a constraint edge would cross one inserted before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrossingConstraint {
+	/// The endpoints of the constrained edge in the way.
+	pub edge: [u32; 2],
+}
+
+impl fmt::Display for CrossingConstraint {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let [a, b] = self.edge;
+		write!(f, "constraint crosses the constrained edge between points {a} and {b}")
+	}
+}
+
+impl core::error::Error for CrossingConstraint {}
+
+/// Delaunay triangulation of a 2D point set, with optional constraint edges that stay in the
+/// mesh whatever the empty circle rule says.
+///
+/// Triangles come out as an index buffer into the points, three indices per triangle, all
+/// counter-clockwise. Repeated points are triangulated once; their later copies are left out of
+/// the index buffer. So are points that rounding places on the hull's boundary while it is being
+/// built, which only happens when coordinates of very different magnitude make the orientation
+/// test inexact.
+///
+/// ```
+/// use maths::geometry::Delaunay;
+/// use maths::Vector2;
+///
+/// let corners = [Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(4.0, 1.0), Vector2::new(0.0, 1.0)];
+/// let points = [&corners[..], &[Vector2::new(2.0, 0.5), Vector2::new(1.0, 0.9)]].concat();
+/// let mut delaunay = Delaunay::new(&points).unwrap();
+/// assert_eq!(delaunay.triangles().len(), 6 * 3);
+/// assert_eq!(delaunay.hull().len(), 4);
+///
+/// // The centre point sits on the diagonal, splitting it in two
+/// delaunay.insert_constraint(0, 2).unwrap();
+/// assert!(delaunay.is_constrained(0, 4) && delaunay.is_constrained(4, 2));
+/// assert!(delaunay.insert_constraint(1, 5).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delaunay {
+	points: Vec<Vector2>,
+	triangles: Vec<u32>,
+	/// The opposite of each half-edge, or `EMPTY` on the hull.
+	halfedges: Vec<u32>,
+	constrained: Vec<bool>,
+	/// A half-edge leaving each point, or `EMPTY` for points left out of the triangulation.
+	incident: Vec<u32>,
+	/// The lowest index holding each point.
+	canonical: Vec<u32>,
+	hull: Vec<u32>,
+}
+
+impl Delaunay {
+	/// Triangulates `points` with a sweep around the seed triangle's circumcenter. Returns `None`
+	/// when there are fewer than three distinct points or they all lie on one line.
+	///
+	/// # Panics
+	///
+	/// Panics if there are more than `u32::MAX - 1` points.
+	pub fn new(points: &[Vector2]) -> Option<Delaunay> {
+		assert!(points.len() < EMPTY as usize, "too many points to index with u32");
+		let count = points.len();
+
+		let mut sorted: Vec<u32> = (0..count as u32).collect();
+		sorted.sort_unstable_by(|&a, &b| {
+			let (pa, pb) = (points[a as usize], points[b as usize]);
+			pa.x.total_cmp(&pb.x).then(pa.y.total_cmp(&pb.y)).then(a.cmp(&b))
+		});
+		let mut canonical: Vec<u32> = (0..count as u32).collect();
+		for pair in sorted.windows(2) {
+			if points[pair[0] as usize] == points[pair[1] as usize] {
+				canonical[pair[1] as usize] = canonical[pair[0] as usize];
+			}
+		}
+		let distinct = || (0..count).filter(|&i| canonical[i] == i as u32);
+
+		// Seed with the point nearest the middle, its nearest neighbour and whichever third point
+		// makes the smallest circle with them
+		let (min, max) = points.iter().fold((Vector2::new(f32::INFINITY, f32::INFINITY), Vector2::new(f32::NEG_INFINITY, f32::NEG_INFINITY)), |(min, max), point| {
+			(min.min(*point), max.max(*point))
+		});
+		let middle = (min + max) * 0.5;
+		let distance_squared = |a: Vector2, b: Vector2| {
+			let (x, y) = (a.x as f64 - b.x as f64, a.y as f64 - b.y as f64);
+			x * x + y * y
+		};
+		let closest = |from: Vector2, skip: usize| {
+			distinct().filter(|&i| i != skip).min_by(|&a, &b| distance_squared(from, points[a]).total_cmp(&distance_squared(from, points[b])))
+		};
+		let i0 = closest(middle, usize::MAX)?;
+		let i1 = closest(points[i0], i0)?;
+		let (p0, p1) = (points[i0], points[i1]);
+		let mut i2 = distinct()
+			.filter(|&i| orient(p0, p1, points[i]) != 0.0)
+			.min_by(|&a, &b| circumcircle(p0, p1, points[a]).1.total_cmp(&circumcircle(p0, p1, points[b]).1))?;
+		let ([x, y], _) = circumcircle(p0, p1, points[i2]);
+		let mut i1 = i1;
+		if orient(p0, p1, points[i2]) < 0.0 {
+			core::mem::swap(&mut i1, &mut i2);
+		}
+		let center = [p0.x as f64 + x, p0.y as f64 + y];
+
+		// Points further from the center than everything before them always lie outside the
+		// hull so far
+		let distances: Vec<f64> = points.iter().map(|point| {
+			let (x, y) = (point.x as f64 - center[0], point.y as f64 - center[1]);
+			x * x + y * y
+		}).collect();
+		let mut order: Vec<u32> = distinct().map(|i| i as u32).collect();
+		order.sort_unstable_by(|&a, &b| distances[a as usize].total_cmp(&distances[b as usize]).then(a.cmp(&b)));
+
+		let triangles = 2 * count;
+		let mut delaunay = Delaunay {
+			points: points.to_vec(),
+			triangles: Vec::with_capacity(3 * triangles),
+			halfedges: Vec::with_capacity(3 * triangles),
+			constrained: Vec::with_capacity(3 * triangles),
+			incident: vec![EMPTY; count],
+			canonical,
+			hull: Vec::new(),
+		};
+
+		// The hull as a linked loop of points, counter-clockwise, with the half-edge leaving each
+		// along it. Points dropped from the hull link to themselves.
+		let (i0, i1, i2) = (i0 as u32, i1 as u32, i2 as u32);
+		let mut hull_next = vec![EMPTY; count];
+		let mut hull_prev = vec![EMPTY; count];
+		let mut hull_edges = vec![EMPTY; count];
+		let hash_size = ceil(sqrt(count as f64)) as usize;
+		let mut hull_hash = vec![EMPTY; hash_size];
+		let hash_key = |point: Vector2| {
+			let (x, y) = (point.x as f64 - center[0], point.y as f64 - center[1]);
+			let slope = x / (fabs(x) + fabs(y));
+			let angle = if y > 0.0 { 3.0 - slope } else { 1.0 + slope } / 4.0;
+			floor(angle * hash_size as f64) as usize % hash_size
+		};
+
+		delaunay.add_triangle([i0, i1, i2], [EMPTY; 3]);
+		for (edge, [point, following]) in [[i0, i1], [i1, i2], [i2, i0]].into_iter().enumerate() {
+			hull_next[point as usize] = following;
+			hull_prev[following as usize] = point;
+			hull_edges[point as usize] = edge as u32;
+			hull_hash[hash_key(points[point as usize])] = point;
+		}
+		let mut hull_start = i0;
+
+		for &i in &order {
+			if i == i0 || i == i1 || i == i2 {
+				continue;
+			}
+			let point = points[i as usize];
+			let outside = |from: u32, to: u32| orient(points[from as usize], points[to as usize], point) < 0.0;
+
+			let key = hash_key(point);
+			let mut start = EMPTY;
+			for j in 0..hash_size {
+				start = hull_hash[(key + j) % hash_size];
+				if start != EMPTY && start != hull_next[start as usize] {
+					break;
+				}
+			}
+			start = hull_prev[start as usize];
+			let mut e = start;
+			while !outside(e, hull_next[e as usize]) {
+				e = hull_next[e as usize];
+				if e == start {
+					break;
+				}
+			}
+			if !outside(e, hull_next[e as usize]) {
+				// On the hull within rounding error, left out of the triangulation
+				continue;
+			}
+
+			// Fan out from the first edge that sees the point, forwards and then backwards
+			let mut n = hull_next[e as usize];
+			let t = delaunay.add_triangle([e, i, n], [EMPTY, EMPTY, hull_edges[e as usize]]);
+			hull_edges[i as usize] = t + 1;
+			hull_edges[e as usize] = t;
+			delaunay.legalize(t + 2, &mut hull_edges);
+			while outside(n, hull_next[n as usize]) {
+				let q = hull_next[n as usize];
+				let t = delaunay.add_triangle([n, i, q], [hull_edges[i as usize], EMPTY, hull_edges[n as usize]]);
+				hull_edges[i as usize] = t + 1;
+				delaunay.legalize(t + 2, &mut hull_edges);
+				hull_next[n as usize] = n;
+				n = q;
+			}
+			if e == start {
+				while outside(hull_prev[e as usize], e) {
+					let q = hull_prev[e as usize];
+					let t = delaunay.add_triangle([q, i, e], [EMPTY, hull_edges[e as usize], hull_edges[q as usize]]);
+					delaunay.legalize(t + 2, &mut hull_edges);
+					hull_edges[q as usize] = t;
+					hull_next[e as usize] = e;
+					e = q;
+				}
+			}
+
+			hull_start = e;
+			hull_prev[i as usize] = e;
+			hull_next[e as usize] = i;
+			hull_prev[n as usize] = i;
+			hull_next[i as usize] = n;
+			hull_hash[hash_key(point)] = i;
+			hull_hash[hash_key(points[e as usize])] = e;
+		}
+
+		// Points on the hull's edges stay linked in, but only its corners are kept
+		let mut point = hull_start;
+		loop {
+			let (before, after) = (hull_prev[point as usize], hull_next[point as usize]);
+			if orient(points[before as usize], points[point as usize], points[after as usize]) != 0.0 {
+				delaunay.hull.push(point);
+			}
+			point = after;
+			if point == hull_start {
+				break;
+			}
+		}
+		Some(delaunay)
+	}
+
+	/// The points the triangulation was built from.
+	pub fn points(&self) -> &[Vector2] {
+		&self.points
+	}
+
+	/// Index buffer with three indices per counter-clockwise triangle.
+	pub fn triangles(&self) -> &[u32] {
+		&self.triangles
+	}
+
+	/// Convex hull of the points, counter-clockwise. Points on its edges are left out.
+	pub fn hull(&self) -> &[u32] {
+		&self.hull
+	}
+
+	/// Whether points `a` and `b` are joined by a constraint edge.
+	pub fn is_constrained(&self, a: u32, b: u32) -> bool {
+		let (a, b) = (self.canonical[a as usize], self.canonical[b as usize]);
+		self.find_edge(a, b).is_some_and(|edge| self.constrained[edge as usize])
+	}
+
+	/// Forces the edge between points `a` and `b` into the triangulation, flipping away the edges
+	/// it crosses and restoring the Delaunay property around it. Points lying on the segment split
+	/// it into several constrained edges.
+	///
+	/// # Errors
+	///
+	/// Returns the constrained edge in the way if the segment crosses one, leaving the
+	/// triangulation unchanged.
+	///
+	/// # Panics
+	///
+	/// Panics if `a` or `b` is out of bounds or was left out of the triangulation.
+	pub fn insert_constraint(&mut self, a: u32, b: u32) -> Result<(), CrossingConstraint> {
+		let (a, b) = (self.canonical[a as usize], self.canonical[b as usize]);
+		for point in [a, b] {
+			assert!(self.incident[point as usize] != EMPTY, "constraint endpoint {point} was left out of the triangulation");
+		}
+
+		// Walk the whole segment first so nothing changes when it turns out to be blocked
+		let mut from = a;
+		while from != b {
+			from = self.walk(from, b)?.0;
+		}
+
+		let mut from = a;
+		while from != b {
+			let (to, crossed) = self.walk(from, b)?;
+			let (start, end) = (self.points[from as usize], self.points[to as usize]);
+			let mut queue = VecDeque::from(crossed);
+			let mut created = Vec::new();
+			while let Some([u, v]) = queue.pop_front() {
+				let edge = self.find_edge(u, v).expect("crossed edges stay in the triangulation until flipped");
+				let opposite = self.halfedges[edge as usize];
+				let (u, v) = (self.triangles[edge as usize], self.triangles[next(edge) as usize]);
+				let (r, s) = (self.triangles[prev(edge) as usize], self.triangles[prev(opposite) as usize]);
+				let [pu, pv, pr, ps] = [u, v, r, s].map(|i| self.points[i as usize]);
+				// Only a strictly convex quad can swap diagonals; come back once its neighbours moved
+				if orient(pr, ps, pu) * orient(pr, ps, pv) >= 0.0 {
+					queue.push_back([u, v]);
+					continue;
+				}
+				self.flip(edge, &mut []);
+				let crosses = orient(start, end, pr) * orient(start, end, ps) < 0.0 && orient(pr, ps, start) * orient(pr, ps, end) < 0.0;
+				if crosses {
+					queue.push_back([r, s]);
+				} else {
+					created.push([r, s]);
+				}
+			}
+
+			let edge = self.find_edge(from, to).expect("constraint edge is in the triangulation");
+			self.constrained[edge as usize] = true;
+			let opposite = self.halfedges[edge as usize];
+			if opposite != EMPTY {
+				self.constrained[opposite as usize] = true;
+			}
+			for [r, s] in created {
+				if let Some(edge) = self.find_edge(r, s) {
+					self.legalize(edge, &mut []);
+				}
+			}
+			from = to;
+		}
+		Ok(())
+	}
+
+	/// Dual of the triangulation, with a cell around each point.
+	pub fn voronoi(&self) -> Voronoi {
+		let vertices = self.triangles.chunks_exact(3).map(|triangle| {
+			let [a, b, c] = [0, 1, 2].map(|i| self.points[triangle[i] as usize]);
+			let ([x, y], _) = circumcircle(a, b, c);
+			Vector2::new((a.x as f64 + x) as f32, (a.y as f64 + y) as f32)
+		}).collect();
+
+		let mut offsets = Vec::with_capacity(self.points.len() + 1);
+		let mut cells = Vec::with_capacity(self.triangles.len());
+		let mut rays = Vec::with_capacity(self.points.len());
+		offsets.push(0);
+		for point in 0..self.points.len() as u32 {
+			let fan = self.fan(point);
+			cells.extend(fan.iter().map(|edge| edge / 3));
+			offsets.push(cells.len() as u32);
+
+			let first = fan.first().copied().filter(|&edge| self.halfedges[edge as usize] == EMPTY);
+			let last = fan.last().map(|&edge| prev(edge)).filter(|&edge| self.halfedges[edge as usize] == EMPTY);
+			rays.push(first.zip(last).map(|(first, last)| {
+				[first, last].map(|edge| {
+					let along = self.points[self.triangles[next(edge) as usize] as usize] - self.points[self.triangles[edge as usize] as usize];
+					Vector2::new(along.y, -along.x).normalized()
+				})
+			}));
+		}
+		Voronoi { vertices, offsets, cells, rays }
+	}
+
+	/// Finds where the segment from `a` towards `b` first reaches a point, with the edges it
+	/// crosses on the way, each given right endpoint first.
+	fn walk(&self, a: u32, b: u32) -> Result<(u32, Vec<[u32; 2]>), CrossingConstraint> {
+		let (start, end) = (self.points[a as usize], self.points[b as usize]);
+		let ahead = |point: u32| {
+			let point = self.points[point as usize];
+			orient(start, end, point) == 0.0 && (point - start).dot(end - start) > 0.0
+		};
+
+		let mut crossing = EMPTY;
+		for edge in self.fan(a) {
+			let (q, r) = (self.triangles[next(edge) as usize], self.triangles[prev(edge) as usize]);
+			if ahead(q) {
+				return Ok((q, Vec::new()));
+			}
+			if ahead(r) {
+				return Ok((r, Vec::new()));
+			}
+			if orient(start, end, self.points[q as usize]) < 0.0 && orient(start, end, self.points[r as usize]) > 0.0 {
+				crossing = next(edge);
+				break;
+			}
+		}
+
+		let mut crossed = Vec::new();
+		loop {
+			let [q, r] = [crossing, next(crossing)].map(|edge| self.triangles[edge as usize]);
+			if self.constrained[crossing as usize] {
+				return Err(CrossingConstraint { edge: [q, r] });
+			}
+			crossed.push([q, r]);
+			let opposite = self.halfedges[crossing as usize];
+			let s = self.triangles[prev(opposite) as usize];
+			let side = orient(start, end, self.points[s as usize]);
+			if s == b || side == 0.0 {
+				return Ok((s, crossed));
+			}
+			crossing = if side < 0.0 { prev(opposite) } else { next(opposite) };
+		}
+	}
+
+	fn add_triangle(&mut self, points: [u32; 3], opposites: [u32; 3]) -> u32 {
+		let t = self.triangles.len() as u32;
+		for (i, (point, opposite)) in points.into_iter().zip(opposites).enumerate() {
+			self.triangles.push(point);
+			self.halfedges.push(EMPTY);
+			self.constrained.push(false);
+			self.link(t + i as u32, opposite);
+			self.incident[point as usize] = t + i as u32;
+		}
+		t
+	}
+
+	fn link(&mut self, a: u32, b: u32) {
+		self.halfedges[a as usize] = b;
+		if b != EMPTY {
+			self.halfedges[b as usize] = a;
+		}
+	}
+
+	/// Swaps the diagonal `edge` of the quad made by its two triangles. Triangles `(p, q, r)` and
+	/// `(q, p, s)` become `(s, q, r)` and `(r, p, s)` in the same slots. Hull half-edges that move
+	/// are updated in `hull_edges` while the hull is being built.
+	fn flip(&mut self, edge: u32, hull_edges: &mut [u32]) {
+		let opposite = self.halfedges[edge as usize];
+		let (edge_prev, opposite_prev) = (prev(edge), prev(opposite));
+		let [p, q, r, s] = [edge, next(edge), edge_prev, opposite_prev].map(|e| self.triangles[e as usize]);
+
+		let (outer_edge, outer_opposite) = (self.halfedges[opposite_prev as usize], self.halfedges[edge_prev as usize]);
+		self.constrained[edge as usize] = self.constrained[opposite_prev as usize];
+		self.constrained[opposite as usize] = self.constrained[edge_prev as usize];
+		self.constrained[edge_prev as usize] = false;
+		self.constrained[opposite_prev as usize] = false;
+		self.triangles[edge as usize] = s;
+		self.triangles[opposite as usize] = r;
+		self.link(edge, outer_edge);
+		self.link(opposite, outer_opposite);
+		self.link(edge_prev, opposite_prev);
+
+		for (outer, point, from, to) in [(outer_edge, s, opposite_prev, edge), (outer_opposite, r, edge_prev, opposite)] {
+			if let Some(hull_edge) = hull_edges.get_mut(point as usize).filter(|hull_edge| outer == EMPTY && **hull_edge == from) {
+				*hull_edge = to;
+			}
+		}
+		self.incident[s as usize] = edge;
+		self.incident[q as usize] = next(edge);
+		self.incident[r as usize] = opposite;
+		self.incident[p as usize] = next(opposite);
+	}
+
+	/// Flips edges from `edge` outwards until every unconstrained edge passes the empty circle
+	/// test.
+	fn legalize(&mut self, edge: u32, hull_edges: &mut [u32]) {
+		let mut stack = vec![edge];
+		while let Some(edge) = stack.pop() {
+			let opposite = self.halfedges[edge as usize];
+			if opposite == EMPTY || self.constrained[edge as usize] {
+				continue;
+			}
+			let [p, q, r, s] = [edge, next(edge), prev(edge), prev(opposite)].map(|e| self.points[self.triangles[e as usize] as usize]);
+			if in_circle(p, q, r, s) <= 0.0 || orient(s, q, r) <= 0.0 || orient(r, p, s) <= 0.0 {
+				continue;
+			}
+			self.flip(edge, hull_edges);
+			stack.extend([edge, next(edge), opposite, next(opposite)]);
+		}
+	}
+
+	/// Half-edges leaving `point` counter-clockwise, starting on the hull if the point is on it.
+	fn fan(&self, point: u32) -> Vec<u32> {
+		let start = self.incident[point as usize];
+		let mut fan = Vec::new();
+		if start == EMPTY {
+			return fan;
+		}
+		let mut first = start;
+		loop {
+			let opposite = self.halfedges[first as usize];
+			if opposite == EMPTY || next(opposite) == start {
+				break;
+			}
+			first = next(opposite);
+		}
+		let mut edge = first;
+		loop {
+			fan.push(edge);
+			edge = self.halfedges[prev(edge) as usize];
+			if edge == EMPTY || edge == first {
+				break;
+			}
+		}
+		fan
+	}
+
+	/// A half-edge between `a` and `b` in either direction.
+	fn find_edge(&self, a: u32, b: u32) -> Option<u32> {
+		self.fan(a).into_iter().find_map(|edge| {
+			if self.triangles[next(edge) as usize] == b {
+				Some(edge)
+			} else if self.triangles[prev(edge) as usize] == b {
+				Some(prev(edge))
+			} else {
+				None
+			}
+		})
+	}
+}
+
+/// Dual of a [`Delaunay`] triangulation, with a vertex at each triangle's circumcenter and a
+/// cell around each point. Without constraints the cells are the points' Voronoi regions; edges
+/// forced in by constraints can make neighbouring cells overlap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Voronoi {
+	vertices: Vec<Vector2>,
+	offsets: Vec<u32>,
+	cells: Vec<u32>,
+	rays: Vec<Option<[Vector2; 2]>>,
+}
+
+impl Voronoi {
+	/// Circumcenter of each triangle, in the order of the triangulation's index buffer.
+	pub fn vertices(&self) -> &[Vector2] {
+		&self.vertices
+	}
+
+	/// Indices into [`Voronoi::vertices`] around the cell of `point`, counter-clockwise. Empty for
+	/// points left out of the triangulation.
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is out of bounds.
+	pub fn cell(&self, point: usize) -> &[u32] {
+		&self.cells[self.offsets[point] as usize..self.offsets[point + 1] as usize]
+	}
+
+	/// For cells of points on the hull, which are unbounded, the unit directions the cell runs
+	/// off to infinity in: from its first vertex, then from its last.
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is out of bounds.
+	pub fn rays(&self, point: usize) -> Option<[Vector2; 2]> {
+		self.rays[point]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use libm::{fabsf, fminf};
+	use rand::rngs::SmallRng;
+	use rand::{Rng, SeedableRng};
+
+	fn area(points: &[Vector2], triangles: &[u32]) -> f64 {
+		triangles.chunks_exact(3).map(|t| orient(points[t[0] as usize], points[t[1] as usize], points[t[2] as usize]) / 2.0).sum()
+	}
+
+	/// Checks the mesh is consistent and every unconstrained edge is locally Delaunay.
+	fn check(delaunay: &Delaunay) {
+		let points = delaunay.points();
+		let triangles = delaunay.triangles();
+		for t in triangles.chunks_exact(3) {
+			assert!(orient(points[t[0] as usize], points[t[1] as usize], points[t[2] as usize]) > 0.0);
+		}
+		for (edge, &opposite) in delaunay.halfedges.iter().enumerate() {
+			let edge = edge as u32;
+			if opposite == EMPTY {
+				continue;
+			}
+			assert_eq!(delaunay.halfedges[opposite as usize], edge);
+			assert_eq!(triangles[edge as usize], triangles[next(opposite) as usize]);
+			assert_eq!(delaunay.constrained[edge as usize], delaunay.constrained[opposite as usize]);
+			if !delaunay.constrained[edge as usize] {
+				let [p, q, r, s] = [edge, next(edge), prev(edge), prev(opposite)].map(|e| points[triangles[e as usize] as usize]);
+				assert!(in_circle(p, q, r, s) <= 0.0, "edge {edge} is not Delaunay");
+			}
+		}
+		let boundary = delaunay.halfedges.iter().filter(|&&opposite| opposite == EMPTY).count();
+		let used = delaunay.incident.iter().filter(|&&edge| edge != EMPTY).count();
+		assert_eq!(triangles.len() / 3, 2 * used - 2 - boundary);
+	}
+
+	#[test]
+	fn random() {
+		let mut rng = SmallRng::seed_from_u64(0x5EED);
+		for count in [3, 4, 10, 100, 2000] {
+			let points: Vec<Vector2> = (0..count).map(|_| Vector2::new(rng.random_range(-100.0..100.0), rng.random_range(-100.0..100.0))).collect();
+			let delaunay = Delaunay::new(&points).unwrap();
+			check(&delaunay);
+			let hull = crate::geometry::convex_hull_2d(&points);
+			assert_eq!(delaunay.hull().len(), hull.len());
+			let hull_area: f64 = (0..hull.len()).map(|i| orient(Vector2::new(0.0, 0.0), hull[i], hull[(i + 1) % hull.len()]) / 2.0).sum();
+			assert!(fabs(area(&points, delaunay.triangles()) - hull_area) < 1e-6 * hull_area);
+		}
+	}
+
+	#[test]
+	fn degenerate() {
+		// A grid is all cocircular quads and collinear hull points
+		let grid: Vec<Vector2> = (0..100).map(|i| Vector2::new((i % 10) as f32 * 0.1, (i / 10) as f32 * 0.1)).collect();
+		let delaunay = Delaunay::new(&grid).unwrap();
+		check(&delaunay);
+		assert_eq!(delaunay.triangles().len(), 162 * 3);
+		assert_eq!(delaunay.hull().len(), 4);
+		assert!(fabs(area(&grid, delaunay.triangles()) - 0.81) < 1e-5);
+
+		let mut repeated = grid.clone();
+		repeated.extend_from_slice(&grid[..50]);
+		let delaunay = Delaunay::new(&repeated).unwrap();
+		check(&delaunay);
+		assert_eq!(delaunay.triangles().len(), 162 * 3);
+		assert!(delaunay.triangles().iter().all(|&i| i < 100));
+
+		let line: Vec<Vector2> = (0..10).map(|i| Vector2::new(i as f32, 2.0 * i as f32)).collect();
+		assert!(Delaunay::new(&line).is_none());
+		assert!(Delaunay::new(&[Vector2::new(1.0, 1.0); 5]).is_none());
+		assert!(Delaunay::new(&grid[..2]).is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "constraint endpoint 4 was left out of the triangulation")]
+	fn left_out() {
+		// Coordinates this far apart in magnitude round point 4 onto the hull
+		let points = [
+			Vector2::new(-139455.0, -0.0015289674),
+			Vector2::new(122.390015, 2.251253e-7),
+			Vector2::new(52787.78, 2.2638109e-5),
+			Vector2::new(6362.164, -4.6781856e-6),
+			Vector2::new(110.01074, -6.517956e-8),
+			Vector2::new(219133.25, 1.9721e-5),
+		];
+		let mut delaunay = Delaunay::new(&points).unwrap();
+		assert!(!delaunay.triangles().contains(&4) && delaunay.voronoi().cell(4).is_empty());
+		assert!(!delaunay.is_constrained(4, 1));
+		delaunay.insert_constraint(0, 4).ok();
+	}
+
+	#[test]
+	fn constraints() {
+		let mut rng = SmallRng::seed_from_u64(7);
+		let mut points: Vec<Vector2> = (0..500).map(|_| Vector2::new(rng.random_range(0.0..10.0), rng.random_range(0.0..10.0))).collect();
+		// A zigzag across the cloud and a few long chords through grid points
+		let zigzag: Vec<u32> = (0..8).map(|i| {
+			points.push(Vector2::new(0.5 + i as f32 * 1.25, if i % 2 == 0 { 1.0 } else { 9.0 }));
+			points.len() as u32 - 1
+		}).collect();
+		let mut delaunay = Delaunay::new(&points).unwrap();
+		for pair in zigzag.windows(2) {
+			delaunay.insert_constraint(pair[0], pair[1]).unwrap();
+		}
+		check(&delaunay);
+		assert!(zigzag.windows(2).all(|pair| delaunay.is_constrained(pair[1], pair[0])));
+
+		// Crossing the zigzag fails without touching anything
+		let before = delaunay.clone();
+		let error = delaunay.insert_constraint(zigzag[0], zigzag[3]).unwrap_err();
+		assert!(zigzag.windows(2).any(|pair| error.edge == [pair[0], pair[1]] || error.edge == [pair[1], pair[0]]));
+		assert_eq!(delaunay, before);
+
+		// Constraints through points split at them
+		let grid: Vec<Vector2> = (0..36).map(|i| Vector2::new((i % 6) as f32, (i / 6) as f32)).collect();
+		let mut delaunay = Delaunay::new(&grid).unwrap();
+		delaunay.insert_constraint(0, 35).unwrap();
+		delaunay.insert_constraint(5, 30).unwrap_err();
+		delaunay.insert_constraint(1, 17).unwrap();
+		check(&delaunay);
+		assert!(delaunay.is_constrained(14, 21) && delaunay.is_constrained(1, 9) && delaunay.is_constrained(17, 9));
+		assert!(!delaunay.is_constrained(0, 1));
+		assert!(fabs(area(&grid, delaunay.triangles()) - 25.0) < 1e-9);
+	}
+
+	#[test]
+	fn voronoi() {
+		let mut rng = SmallRng::seed_from_u64(11);
+		let points: Vec<Vector2> = (0..300).map(|_| Vector2::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0))).collect();
+		let delaunay = Delaunay::new(&points).unwrap();
+		let voronoi = delaunay.voronoi();
+		assert_eq!(voronoi.vertices().len(), delaunay.triangles().len() / 3);
+
+		let mut unbounded = 0;
+		for (i, point) in points.iter().enumerate() {
+			let cell = voronoi.cell(i);
+			assert!(cell.len() >= 2);
+			// Each vertex is as close to this point as to any other
+			for &vertex in cell {
+				let vertex = voronoi.vertices()[vertex as usize];
+				let nearest = points.iter().map(|other| (*other - vertex).magnitude()).fold(f32::INFINITY, fminf);
+				assert!((*point - vertex).magnitude() - nearest < 1e-3);
+			}
+			if let Some([first, last]) = voronoi.rays(i) {
+				unbounded += 1;
+				assert!(fabsf(first.magnitude() - 1.0) < 1e-5 && fabsf(last.magnitude() - 1.0) < 1e-5);
+			} else {
+				let corners: Vec<Vector2> = cell.iter().map(|&vertex| voronoi.vertices()[vertex as usize]).collect();
+				let turns = (0..corners.len()).map(|j| orient(*point, corners[j], corners[(j + 1) % corners.len()]));
+				assert!(turns.into_iter().all(|turn| turn >= 0.0));
+			}
+		}
+		assert_eq!(unbounded, delaunay.hull().len());
+	}
+}
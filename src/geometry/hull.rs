@@ -0,0 +1,468 @@
+use super::predicates::orient;
+use crate::collision::{Support, Triangle};
+use crate::{Plane, Vector2, Vector3};
+use alloc::vec::Vec;
+use libm::{fabs, fabsf, fmaxf};
+
+/// Convex hull of `points` with Andrew's monotone chain, counter-clockwise from the point with
+/// the lowest x, then y. Points on the hull's edges are left out, exactly so for coordinates of
+/// similar magnitude, while mixing very small and very large ones can misjudge nearly collinear
+/// points. Fewer than three points come back as the distinct points among them.
+pub fn convex_hull_2d(points: &[Vector2]) -> Vec<Vector2> {
+	let mut sorted = points.to_vec();
+	sorted.sort_unstable_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+	sorted.dedup();
+	if sorted.len() < 3 {
+		return sorted;
+	}
+
+	let turns_left = |a: Vector2, b: Vector2, c: Vector2| orient(a, b, c) > 0.0;
+
+	let mut hull: Vec<Vector2> = Vec::with_capacity(sorted.len() + 1);
+	for point in &sorted {
+		while hull.len() >= 2 && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], *point) {
+			hull.pop();
+		}
+		hull.push(*point);
+	}
+	// Back along the upper chain, keeping the lower one
+	let lower = hull.len() + 1;
+	for point in sorted.iter().rev().skip(1) {
+		while hull.len() >= lower && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], *point) {
+			hull.pop();
+		}
+		hull.push(*point);
+	}
+	// The upper chain ends back at the first point
+	hull.pop();
+	hull
+}
+
+/// Convex hull of a 3D point cloud, triangulated with every face counter-clockwise seen from
+/// outside. Only points on the hull are kept as vertices.
+///
+/// ```
+/// use maths::geometry::ConvexHull;
+/// use maths::Vector3;
+///
+/// let mut points = vec![Vector3::ZERO, Vector3::new(0.1, 0.2, 0.3)];
+/// for corner in 0..8 {
+///     points.push(Vector3::new((corner & 1) as f32, (corner >> 1 & 1) as f32, (corner >> 2) as f32));
+/// }
+/// let hull = ConvexHull::new(&points).unwrap();
+/// assert_eq!((hull.vertices().len(), hull.faces().len()), (8, 12));
+/// assert!(hull.contains_point(Vector3::splat(0.5)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvexHull {
+	vertices: Vec<Vector3>,
+	faces: Vec<[u32; 3]>,
+	planes: Vec<Plane>,
+	tolerance: f32,
+}
+
+struct Face {
+	vertices: [u32; 3],
+	/// The face across each edge, edge `i` runs from `vertices[i]` to the next vertex.
+	neighbors: [u32; 3],
+	plane: WidePlane,
+	outside: Vec<u32>,
+	alive: bool,
+}
+
+impl Face {
+	fn new(points: &[Vector3], vertices: [u32; 3], neighbors: [u32; 3]) -> Face {
+		let [a, b, c] = vertices.map(|vertex| points[vertex as usize]);
+		Face { vertices, neighbors, plane: WidePlane::new(a, b, c), outside: Vec::new(), alive: true }
+	}
+}
+
+/// A face's plane in double precision. Faces between nearly coincident points are slivers whose
+/// single precision normal can be off by a lot, which would misjudge points far across the hull.
+#[derive(Clone, Copy)]
+struct WidePlane {
+	normal: [f64; 3],
+	distance: f64,
+}
+
+impl WidePlane {
+	fn new(a: Vector3, b: Vector3, c: Vector3) -> WidePlane {
+		let [a, b, c] = [a, b, c].map(|point| [point.x as f64, point.y as f64, point.z as f64]);
+		let (ab, ac) = (core::array::from_fn::<f64, 3, _>(|i| b[i] - a[i]), core::array::from_fn::<f64, 3, _>(|i| c[i] - a[i]));
+		let normal = [ab[1] * ac[2] - ab[2] * ac[1], ab[2] * ac[0] - ab[0] * ac[2], ab[0] * ac[1] - ab[1] * ac[0]];
+		// The corners are further than the tolerance from each other's lines, so this is never zero
+		let magnitude = libm::sqrt(normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]);
+		let normal = normal.map(|component| component / magnitude);
+		WidePlane { normal, distance: normal[0] * a[0] + normal[1] * a[1] + normal[2] * a[2] }
+	}
+
+	fn signed_distance(&self, point: Vector3) -> f64 {
+		let n = self.normal;
+		n[0] * point.x as f64 + n[1] * point.y as f64 + n[2] * point.z as f64 - self.distance
+	}
+
+	fn to_plane(self) -> Plane {
+		let normal = Vector3::new(self.normal[0] as f32, self.normal[1] as f32, self.normal[2] as f32);
+		Plane::new(normal, self.distance as f32)
+	}
+}
+
+impl ConvexHull {
+	/// Quickhull with a tolerance that absorbs the rounding error of `points`' magnitude.
+	/// `None` when fewer than four points or all points lie within it of one plane.
+	pub fn new(points: &[Vector3]) -> Option<ConvexHull> {
+		let extent = points.iter().fold(Vector3::ZERO, |extent, point| extent.max(point.abs()));
+		ConvexHull::with_tolerance(points, 3.0 * f32::EPSILON * (extent.x + extent.y + extent.z))
+	}
+
+	/// Quickhull where points within `tolerance` of the hull count as on it and are dropped.
+	/// Larger tolerances merge nearly coplanar points and simplify noisy scans.
+	pub fn with_tolerance(points: &[Vector3], tolerance: f32) -> Option<ConvexHull> {
+		let (mut vertices, mut faces) = quickhull(points, tolerance)?;
+		// A point on an edge or face of the final hull becomes a vertex when it is picked before
+		// the corners around it, dropping those and hulling again leaves only the corners
+		loop {
+			let flat = flat_vertices(&vertices, &faces, tolerance);
+			if !flat.contains(&true) {
+				break;
+			}
+			let corners: Vec<Vector3> = vertices.iter().zip(&flat).filter(|(_, flat)| !**flat).map(|(vertex, _)| *vertex).collect();
+			(vertices, faces) = quickhull(&corners, tolerance)?;
+		}
+		Some(ConvexHull {
+			vertices,
+			planes: faces.iter().map(|face| face.plane.to_plane()).collect(),
+			faces: faces.into_iter().map(|face| face.vertices).collect(),
+			tolerance,
+		})
+	}
+
+	pub fn vertices(&self) -> &[Vector3] {
+		&self.vertices
+	}
+
+	/// Indices into [`ConvexHull::vertices`], coplanar faces are split into several triangles.
+	pub fn faces(&self) -> &[[u32; 3]] {
+		&self.faces
+	}
+
+	/// The outward facing plane of each face, in the same order.
+	pub fn planes(&self) -> &[Plane] {
+		&self.planes
+	}
+
+	/// True when `point` is inside or within the hull's tolerance of it.
+	pub fn contains_point(&self, point: Vector3) -> bool {
+		self.planes.iter().all(|plane| plane.signed_distance(point) <= self.tolerance)
+	}
+}
+
+impl Support for ConvexHull {
+	fn support(&self, direction: Vector3) -> Vector3 {
+		self.vertices.support(direction)
+	}
+}
+
+/// The hull's vertices and its faces indexing them, with neighbors and outside points left stale.
+fn quickhull(points: &[Vector3], tolerance: f32) -> Option<(Vec<Vector3>, Vec<Face>)> {
+	let simplex = initial_simplex(points, tolerance)?;
+	// Faces the eye is above by more than double precision rounding are replaced. Judging this
+	// by `tolerance` instead would leave folds that tilt sliver faces' planes far out of line.
+	let extent = points.iter().fold(Vector3::ZERO, |extent, point| extent.max(point.abs()));
+	let visible = 16.0 * f64::EPSILON * (extent.x + extent.y + extent.z) as f64;
+	let [a, b, c, d] = simplex;
+	let below = WidePlane::new(points[a as usize], points[b as usize], points[c as usize]).signed_distance(points[d as usize]) < 0.0;
+	let (b, c) = if below { (b, c) } else { (c, b) };
+	let mut faces = Vec::from([
+		Face::new(points, [a, b, c], [2, 3, 1]),
+		Face::new(points, [a, c, d], [0, 3, 2]),
+		Face::new(points, [a, d, b], [1, 3, 0]),
+		Face::new(points, [b, d, c], [2, 1, 0]),
+	]);
+	let candidates = (0..points.len() as u32).filter(|point| !simplex.contains(point));
+	let mut pending = Vec::new();
+	assign(points, &mut faces, 0, candidates, tolerance, visible, &mut pending);
+
+	let mut horizon = Vec::new();
+	let mut stack = Vec::new();
+	let mut orphans = Vec::new();
+	while let Some(face) = pending.pop() {
+		if !faces[face].alive {
+			continue;
+		}
+		let plane = faces[face].plane;
+		let distance = |point: &u32| plane.signed_distance(points[*point as usize]);
+		let eye = *faces[face].outside.iter().max_by(|a, b| distance(a).total_cmp(&distance(b))).unwrap();
+		let eye_point = points[eye as usize];
+
+		// Walk the faces the eye sees depth first, crossing the edges of each in order, so the
+		// edges to faces it does not see come out as one loop around the eye
+		horizon.clear();
+		faces[face].alive = false;
+		orphans.append(&mut faces[face].outside);
+		stack.push((face, 0, 3));
+		while let Some((face, edge, remaining)) = stack.last_mut() {
+			if *remaining == 0 {
+				stack.pop();
+				continue;
+			}
+			let (face, crossed) = (*face, *edge);
+			*edge = (*edge + 1) % 3;
+			*remaining -= 1;
+
+			let neighbor = faces[face].neighbors[crossed] as usize;
+			if !faces[neighbor].alive {
+				continue;
+			}
+			let back = faces[neighbor].neighbors.iter().position(|other| *other as usize == face).unwrap();
+			if faces[neighbor].plane.signed_distance(eye_point) > visible {
+				faces[neighbor].alive = false;
+				orphans.append(&mut faces[neighbor].outside);
+				stack.push((neighbor, (back + 1) % 3, 2));
+			} else {
+				let vertices = faces[face].vertices;
+				horizon.push((vertices[crossed], vertices[(crossed + 1) % 3], neighbor, back));
+			}
+		}
+
+		// Cone from the eye over the horizon, each new face sits between its neighbors in the loop
+		let first = faces.len();
+		let count = horizon.len();
+		for (i, (a, b, neighbor, back)) in horizon.iter().enumerate() {
+			let next = first + (i + 1) % count;
+			let previous = first + (i + count - 1) % count;
+			faces[*neighbor].neighbors[*back] = (first + i) as u32;
+			faces.push(Face::new(points, [*a, *b, eye], [*neighbor as u32, next as u32, previous as u32]));
+		}
+		assign(points, &mut faces, first, orphans.drain(..).filter(|point| *point != eye), tolerance, visible, &mut pending);
+	}
+
+	let mut remap = Vec::from_iter(core::iter::repeat_n(u32::MAX, points.len()));
+	let mut vertices = Vec::new();
+	faces.retain(|face| face.alive);
+	for face in &mut faces {
+		face.vertices = face.vertices.map(|vertex| {
+			if remap[vertex as usize] == u32::MAX {
+				remap[vertex as usize] = vertices.len() as u32;
+				vertices.push(points[vertex as usize]);
+			}
+			remap[vertex as usize]
+		});
+	}
+	Some((vertices, faces))
+}
+
+/// Vertices whose faces' normals all lie within `tolerance` of one plane, so they sit on an
+/// edge or inside a face of the hull instead of at a corner.
+fn flat_vertices(vertices: &[Vector3], faces: &[Face], tolerance: f32) -> Vec<bool> {
+	let mut incident: Vec<(u32, u32)> = faces.iter().enumerate().flat_map(|(i, face)| face.vertices.map(|vertex| (vertex, i as u32))).collect();
+	incident.sort_unstable();
+	let mut flat = Vec::from_iter(core::iter::repeat_n(false, vertices.len()));
+	for group in incident.chunk_by(|a, b| a.0 == b.0) {
+		let vertex = vertices[group[0].0 as usize];
+		let normals = || group.iter().map(|(_, face)| faces[*face as usize].plane.normal);
+		// Angles between normals become distances over the length of the vertex's edges
+		let reach = group
+			.iter()
+			.flat_map(|(_, face)| faces[*face as usize].vertices)
+			.map(|other| (vertices[other as usize] - vertex).magnitude())
+			.fold(0.0, fmaxf) as f64;
+
+		// The two normals furthest apart span the plane the rest should lie in
+		let (mut axis, mut spread) = ([0.0; 3], 0.0);
+		for (i, a) in normals().enumerate() {
+			for b in normals().skip(i + 1) {
+				let cross = [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+				let magnitude = libm::sqrt(cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]);
+				if magnitude > spread {
+					(axis, spread) = (cross.map(|component| component / magnitude), magnitude);
+				}
+			}
+		}
+		let tolerance = tolerance as f64;
+		flat[group[0].0 as usize] = spread * reach <= tolerance
+			|| normals().all(|normal| fabs(normal[0] * axis[0] + normal[1] * axis[1] + normal[2] * axis[2]) * reach <= tolerance);
+	}
+	flat
+}
+
+/// Four points spanning a tetrahedron taller than `tolerance` in every direction, the extremes
+/// along the axes first.
+fn initial_simplex(points: &[Vector3], tolerance: f32) -> Option<[u32; 4]> {
+	if points.is_empty() {
+		return None;
+	}
+	let mut extremes = [0u32; 6];
+	for (i, point) in points.iter().enumerate() {
+		for (axis, extreme) in extremes.chunks_exact_mut(2).enumerate() {
+			let value = |point: &Vector3| [point.x, point.y, point.z][axis];
+			if value(point) < value(&points[extreme[0] as usize]) {
+				extreme[0] = i as u32;
+			}
+			if value(point) > value(&points[extreme[1] as usize]) {
+				extreme[1] = i as u32;
+			}
+		}
+	}
+
+	let furthest = |distance: &dyn Fn(Vector3) -> f32| {
+		let (index, distance) = points
+			.iter()
+			.enumerate()
+			.map(|(i, point)| (i as u32, distance(*point)))
+			.max_by(|a, b| a.1.total_cmp(&b.1))?;
+		(distance > tolerance).then_some(index)
+	};
+
+	let (a, b) = extremes
+		.chunks_exact(2)
+		.map(|extreme| (extreme[0], extreme[1]))
+		.max_by(|x, y| {
+			let length = |(a, b): (u32, u32)| (points[b as usize] - points[a as usize]).magnitude();
+			length(*x).total_cmp(&length(*y))
+		})?;
+	let (pa, pb) = (points[a as usize], points[b as usize]);
+	if (pb - pa).magnitude() <= tolerance {
+		return None;
+	}
+	let axis = (pb - pa).normalized();
+	let c = furthest(&|point| {
+		let offset = point - pa;
+		(offset - axis * offset.dot(axis)).magnitude()
+	})?;
+	let plane = WidePlane::new(pa, pb, points[c as usize]);
+	let d = furthest(&|point| fabsf(plane.signed_distance(point) as f32))?;
+	Some([a, b, c, d])
+}
+
+/// Hands each of `candidates` to the face from `first` on that it is furthest outside of, or
+/// drops it when it is within `tolerance` of them, queueing faces in `pending` as they get their
+/// first point. Being close to a face's plane is not enough, near the sharp rim of a flat hull
+/// that still leaves a point well outside it, so only points below every plane or within
+/// `visible`, the planes' rounding, are dropped without also checking the triangles.
+fn assign(points: &[Vector3], faces: &mut [Face], first: usize, candidates: impl Iterator<Item = u32>, tolerance: f32, visible: f64, pending: &mut Vec<usize>) {
+	for point in candidates {
+		let position = points[point as usize];
+		let distance = |face: &Face| face.plane.signed_distance(position);
+		let Some((face, furthest)) = faces[first..]
+			.iter()
+			.enumerate()
+			.map(|(i, face)| (first + i, distance(face)))
+			.max_by(|a, b| a.1.total_cmp(&b.1))
+		else {
+			continue;
+		};
+		let outside = furthest > tolerance as f64
+			|| furthest > visible
+				&& faces[first..].iter().any(|face| {
+					let [a, b, c] = face.vertices.map(|vertex| points[vertex as usize]);
+					distance(face) > visible && Triangle::new(a, b, c).distance_squared(position) > tolerance * tolerance
+				});
+		if outside {
+			if faces[face].outside.is_empty() {
+				pending.push(face);
+			}
+			faces[face].outside.push(point);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::geometry::{convex_hull_2d, ConvexHull};
+	use crate::{Vector2, Vector3};
+	use alloc::vec::Vec;
+	use rand::rngs::SmallRng;
+	use rand::{Rng, SeedableRng};
+
+	#[test]
+	fn outline() {
+		let points = [
+			Vector2::new(1.0, 1.0),
+			Vector2::new(2.0, 0.0),
+			Vector2::new(0.0, 0.0),
+			Vector2::new(2.0, 2.0),
+			Vector2::new(1.0, 0.0),
+			Vector2::new(0.0, 2.0),
+			Vector2::new(2.0, 2.0),
+			Vector2::new(0.5, 1.5),
+		];
+		let square = [Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(2.0, 2.0), Vector2::new(0.0, 2.0)];
+		assert_eq!(convex_hull_2d(&points), square);
+
+		let line = [Vector2::new(2.0, 2.0), Vector2::ZERO, Vector2::ONE, Vector2::new(3.0, 3.0)];
+		assert_eq!(convex_hull_2d(&line), [Vector2::ZERO, Vector2::new(3.0, 3.0)]);
+		assert_eq!(convex_hull_2d(&[Vector2::ONE; 4]), [Vector2::ONE]);
+		assert_eq!(convex_hull_2d(&[]), []);
+
+		// Barely inside an edge is dropped, barely outside kept
+		let nudged = [Vector2::new(0.0, 0.0), Vector2::new(1.0, 1e-8), Vector2::new(2.0, 0.0), Vector2::new(1.0, 1.0)];
+		assert_eq!(convex_hull_2d(&nudged).len(), 3);
+		let nudged = [Vector2::new(0.0, 0.0), Vector2::new(1.0, -1e-8), Vector2::new(2.0, 0.0), Vector2::new(1.0, 1.0)];
+		assert_eq!(convex_hull_2d(&nudged).len(), 4);
+
+		// Far from the origin, with a collinear point on each edge
+		let offset = Vector2::new(10000.0, 10000.0);
+		let far: Vec<Vector2> = points.iter().map(|&point| point * 2.5 + offset).collect();
+		assert_eq!(convex_hull_2d(&far), square.map(|corner| corner * 2.5 + offset));
+	}
+
+	fn assert_closed(hull: &ConvexHull, points: &[Vector3]) {
+		let mut edges: Vec<(u32, u32)> = hull.faces().iter().flat_map(|[a, b, c]| [(*a, *b), (*b, *c), (*c, *a)]).collect();
+		edges.sort_unstable();
+		for (a, b) in &edges {
+			assert!(edges.binary_search(&(*b, *a)).is_ok(), "edge {a} {b} has one face");
+		}
+		assert_eq!(hull.vertices().len() + hull.faces().len() - edges.len() / 2, 2);
+		for point in points {
+			assert!(hull.contains_point(*point), "{point:?} is outside");
+		}
+		for (face, plane) in hull.faces().iter().zip(hull.planes()) {
+			assert!(hull.vertices().iter().all(|vertex| plane.signed_distance(*vertex) <= 1e-5));
+			assert!(face.iter().all(|vertex| plane.signed_distance(hull.vertices()[*vertex as usize]).abs() <= 1e-5));
+		}
+	}
+
+	#[test]
+	fn clouds() {
+		let mut rng = SmallRng::seed_from_u64(5);
+		let sphere: Vec<Vector3> = (0..1000)
+			.map(|_| Vector3::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0)).normalized() * 3.0)
+			.collect();
+		let hull = ConvexHull::new(&sphere).unwrap();
+		assert_closed(&hull, &sphere);
+		assert_eq!(hull.vertices().len(), 1000);
+
+		let cube: Vec<Vector3> = (0..2000).map(|_| Vector3::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0))).collect();
+		assert_closed(&ConvexHull::new(&cube).unwrap(), &cube);
+	}
+
+	#[test]
+	fn degenerate() {
+		// A grid has points on every face and edge and at each corner several times over
+		let mut grid = Vec::new();
+		for x in 0..5 {
+			for y in 0..5 {
+				for z in 0..5 {
+					grid.extend([Vector3::new(x as f32, y as f32, z as f32); 2]);
+				}
+			}
+		}
+		let hull = ConvexHull::new(&grid).unwrap();
+		assert_closed(&hull, &grid);
+		assert_eq!((hull.vertices().len(), hull.faces().len()), (8, 12));
+
+		// Noise below the tolerance is absorbed
+		let mut rng = SmallRng::seed_from_u64(9);
+		let noisy: Vec<Vector3> = grid.iter().map(|point| *point + Vector3::new(rng.random_range(-1e-3..1e-3), 0.0, 0.0)).collect();
+		let hull = ConvexHull::with_tolerance(&noisy, 0.01).unwrap();
+		assert!(hull.vertices().len() <= 16);
+		assert!(noisy.iter().all(|point| hull.contains_point(*point)));
+
+		let flat: Vec<Vector3> = grid.iter().map(|point| Vector3::new(point.x, point.y, 1.0)).collect();
+		assert_eq!(ConvexHull::new(&flat), None);
+		assert_eq!(ConvexHull::new(&[Vector3::ONE; 10]), None);
+		assert_eq!(ConvexHull::new(&[Vector3::ZERO, Vector3::UP, Vector3::RIGHT]), None);
+		assert_eq!(ConvexHull::new(&[]), None);
+	}
+}
@@ -0,0 +1,22 @@
+//! Computational geometry over point sets, enabled by the `alloc` feature.
+//!
+//! [`convex_hull_2d`] and [`ConvexHull`] wrap points in their convex hull. Points on the hull's
+//! boundary, including repeated points, are left out of it rather than producing slivers. The 2D
+//! hull decides this exactly for coordinates of similar magnitude, the 3D one within a small
+//! tolerance so near-coplanar faces come out clean.
+//!
+//! [`triangulate_polygon`] meshes polygons with holes by ear clipping, and [`Delaunay`]
+//! triangulates point sets with optional constraint edges, with its [`Voronoi`] dual. Both hand
+//! back `u32` index buffers of counter-clockwise triangles, ready for upload alongside the points.
+//! Their orientation tests are exact while the coordinates are of similar magnitude, their
+//! differences fitting in about 26 bits, so snapped grids and collinear runs are handled without
+//! tolerances. Mixing very small and very large coordinates can misjudge nearly collinear points.
+
+mod delaunay;
+mod hull;
+mod polygon;
+mod predicates;
+
+pub use delaunay::{CrossingConstraint, Delaunay, Voronoi};
+pub use hull::{convex_hull_2d, ConvexHull};
+pub use polygon::triangulate_polygon;
@@ -0,0 +1,288 @@
+use super::predicates::orient;
+use crate::Vector2;
+use alloc::vec::Vec;
+use libm::{fabsf, fmaxf};
+
+/// Triangulates a simple polygon with holes by ear clipping, returning an index buffer with three
+/// indices per counter-clockwise triangle.
+///
+/// Indices run through `outline` and then each hole in turn, so the vertex buffer is the rings
+/// laid end to end. Rings may wind either way and should not repeat their first point at the
+/// end. Holes are joined to the outline by bridge edges before clipping, so they must lie inside
+/// it without crossing it or each other. Collinear and repeated vertices are skipped over;
+/// self-intersecting rings still produce triangles, just not ones that cover the polygon.
+///
+/// ```
+/// use maths::geometry::triangulate_polygon;
+/// use maths::Vector2;
+///
+/// let outline = [Vector2::new(0.0, 0.0), Vector2::new(3.0, 0.0), Vector2::new(3.0, 3.0), Vector2::new(0.0, 3.0)];
+/// let hole = [Vector2::new(1.0, 1.0), Vector2::new(2.0, 1.0), Vector2::new(2.0, 2.0), Vector2::new(1.0, 2.0)];
+/// let indices = triangulate_polygon(&outline, &[&hole]);
+/// assert_eq!(indices.len(), 8 * 3);
+/// assert!(indices.iter().all(|&i| i < 8));
+/// ```
+pub fn triangulate_polygon(outline: &[Vector2], holes: &[&[Vector2]]) -> Vec<u32> {
+	let mut points = outline.to_vec();
+	let mut rings = Vec::with_capacity(holes.len());
+	for hole in holes {
+		rings.push(points.len() as u32..(points.len() + hole.len()) as u32);
+		points.extend_from_slice(hole);
+	}
+
+	let mut polygon = wound(0..outline.len() as u32, &points, true);
+	if polygon.len() < 3 {
+		return Vec::new();
+	}
+	// Bridging holes from the rightmost in means each bridge only has the outline and the holes
+	// already joined to it in the way
+	let mut holes: Vec<Vec<u32>> = rings.into_iter().map(|ring| wound(ring, &points, false)).filter(|hole| hole.len() >= 3).collect();
+	let rightmost = |hole: &Vec<u32>| hole.iter().map(|&i| points[i as usize].x).fold(f32::NEG_INFINITY, fmaxf);
+	holes.sort_by(|a, b| rightmost(b).total_cmp(&rightmost(a)));
+	for hole in holes {
+		bridge(&mut polygon, &hole, &points);
+	}
+	clip_ears(&polygon, &points)
+}
+
+/// The ring's indices, reversed if need be to wind counter-clockwise or clockwise.
+fn wound(ring: core::ops::Range<u32>, points: &[Vector2], counter_clockwise: bool) -> Vec<u32> {
+	let mut ring: Vec<u32> = ring.collect();
+	let origin = Vector2::new(0.0, 0.0);
+	let area: f64 = (0..ring.len()).map(|i| orient(origin, points[ring[i] as usize], points[ring[(i + 1) % ring.len()] as usize])).sum();
+	if (area > 0.0) != counter_clockwise {
+		ring.reverse();
+	}
+	ring
+}
+
+/// Splices `hole` into `polygon` along an edge from the hole's rightmost vertex to a polygon vertex
+/// it can see, following Eberly's "Triangulation by Ear Clipping".
+fn bridge(polygon: &mut Vec<u32>, hole: &[u32], points: &[Vector2]) {
+	let point = |i: usize| points[polygon[i] as usize];
+	let start = (0..hole.len()).max_by(|&a, &b| points[hole[a] as usize].x.total_cmp(&points[hole[b] as usize].x)).unwrap_or(0);
+	let m = points[hole[start] as usize];
+
+	// Nearest edge the ray from `m` towards +x hits, among the edges facing it from the right
+	let mut hit: Option<(f64, usize)> = None;
+	for i in 0..polygon.len() {
+		let (a, b) = (point(i), point((i + 1) % polygon.len()));
+		if !(a.y <= m.y && m.y <= b.y && a.y < b.y) {
+			continue;
+		}
+		let x = a.x as f64 + (m.y as f64 - a.y as f64) * (b.x as f64 - a.x as f64) / (b.y as f64 - a.y as f64);
+		if x >= m.x as f64 && hit.is_none_or(|(nearest, _)| x < nearest) {
+			let candidate = if m.y == a.y { i } else if m.y == b.y || b.x > a.x { (i + 1) % polygon.len() } else { i };
+			hit = Some((x, candidate));
+		}
+	}
+	let Some((x, mut candidate)) = hit else {
+		return;
+	};
+
+	// Vertices inside the triangle between `m`, the hit and the candidate block the view to it;
+	// the one closest in angle to the ray is visible instead
+	let p = point(candidate);
+	if p.y != m.y {
+		let hit = Vector2::new(x as f32, m.y);
+		let (a, b, c) = if p.y > m.y { (m, hit, p) } else { (m, p, hit) };
+		let mut best = (lean(p - m), (p - m).x);
+		for i in 0..polygon.len() {
+			let v = point(i);
+			if i == candidate || v == m || v.x < m.x {
+				continue;
+			}
+			if orient(a, b, v) >= 0.0 && orient(b, c, v) >= 0.0 && orient(c, a, v) >= 0.0 && locally_inside(polygon, i, m, points) {
+				let key = (lean(v - m), (v - m).x);
+				if key < best {
+					best = key;
+					candidate = i;
+				}
+			}
+		}
+	}
+
+	let ring = hole[start..].iter().chain(&hole[..=start]);
+	let spliced: Vec<u32> = ring.copied().chain([polygon[candidate]]).collect();
+	polygon.splice(candidate + 1..candidate + 1, spliced);
+}
+
+/// How far `offset` leans off the +x axis.
+fn lean(offset: Vector2) -> f32 {
+	fabsf(offset.y) / offset.x
+}
+
+/// Whether the diagonal from the vertex at `i` towards `to` starts inside the polygon.
+fn locally_inside(polygon: &[u32], i: usize, to: Vector2, points: &[Vector2]) -> bool {
+	let len = polygon.len();
+	let [prev, vertex, next] = [(i + len - 1) % len, i, (i + 1) % len].map(|i| points[polygon[i] as usize]);
+	if orient(prev, vertex, next) >= 0.0 {
+		orient(vertex, to, next) <= 0.0 && orient(vertex, prev, to) <= 0.0
+	} else {
+		orient(vertex, to, prev) > 0.0 || orient(vertex, next, to) > 0.0
+	}
+}
+
+/// Clips ears off a counter-clockwise ring until one triangle is left.
+fn clip_ears(ring: &[u32], points: &[Vector2]) -> Vec<u32> {
+	let len = ring.len();
+	let mut next: Vec<usize> = (1..=len).map(|i| i % len).collect();
+	let mut prev: Vec<usize> = (0..len).map(|i| (i + len - 1) % len).collect();
+	let point = |i: usize| points[ring[i] as usize];
+	let mut indices = Vec::with_capacity(3 * len.saturating_sub(2));
+
+	let mut remaining = len;
+	let mut current = 0;
+	let mut misses = 0;
+	while remaining > 3 {
+		let (before, after) = (prev[current], next[current]);
+		let (a, b, c) = (point(before), point(current), point(after));
+		let turn = orient(a, b, c);
+
+		let ear = turn > 0.0 && {
+			// No other vertex may sit inside or on the ear, copies of its own corners aside
+			let mut other = next[after];
+			loop {
+				if other == before {
+					break true;
+				}
+				let v = point(other);
+				if v != a && v != b && v != c && orient(a, b, v) >= 0.0 && orient(b, c, v) >= 0.0 && orient(c, a, v) >= 0.0 {
+					break false;
+				}
+				other = next[other];
+			}
+		};
+		// Past a full lap without an ear the ring is degenerate or self-intersecting; clip anyway
+		// to make progress
+		if ear || turn == 0.0 || misses > remaining {
+			if turn > 0.0 {
+				indices.extend([ring[before], ring[current], ring[after]]);
+			}
+			next[before] = after;
+			prev[after] = before;
+			remaining -= 1;
+			misses = 0;
+			current = after;
+		} else {
+			misses += 1;
+			current = after;
+		}
+	}
+
+	let (a, b) = (prev[current], next[current]);
+	if orient(point(a), point(current), point(b)) > 0.0 {
+		indices.extend([ring[a], ring[current], ring[b]]);
+	}
+	indices
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use core::f32::consts::TAU;
+	use libm::{cosf, fabs, sinf};
+	use rand::rngs::SmallRng;
+	use rand::{Rng, SeedableRng};
+
+	fn area(points: &[Vector2]) -> f64 {
+		let origin = Vector2::new(0.0, 0.0);
+		(0..points.len()).map(|i| orient(origin, points[i], points[(i + 1) % points.len()])).sum::<f64>() / 2.0
+	}
+
+	fn triangulated_area(points: &[Vector2], indices: &[u32]) -> f64 {
+		indices.chunks_exact(3).map(|t| {
+			let turn = orient(points[t[0] as usize], points[t[1] as usize], points[t[2] as usize]);
+			assert!(turn > 0.0);
+			turn / 2.0
+		}).sum()
+	}
+
+	/// A star-shaped ring around `center`, clockwise or counter-clockwise.
+	fn star(rng: &mut SmallRng, center: Vector2, radius: core::ops::Range<f32>, count: usize, clockwise: bool) -> Vec<Vector2> {
+		let mut ring: Vec<Vector2> = (0..count).map(|i| {
+			let angle = i as f32 / count as f32 * TAU;
+			center + Vector2::new(cosf(angle), sinf(angle)) * rng.random_range(radius.clone())
+		}).collect();
+		if clockwise {
+			ring.reverse();
+		}
+		ring
+	}
+
+	#[test]
+	fn simple() {
+		let mut rng = SmallRng::seed_from_u64(3);
+		for count in [3, 4, 5, 20, 200] {
+			for clockwise in [false, true] {
+				let outline = star(&mut rng, Vector2::new(1.0, -2.0), 0.2..5.0, count, clockwise);
+				let indices = triangulate_polygon(&outline, &[]);
+				assert_eq!(indices.len(), 3 * (count - 2));
+				assert!(fabs(triangulated_area(&outline, &indices) - fabs(area(&outline))) < 1e-4);
+			}
+		}
+
+		// A comb, with every other vertex reflex
+		let mut comb = Vec::new();
+		for i in 0..20 {
+			comb.extend([Vector2::new(i as f32, 0.0), Vector2::new(i as f32 + 0.5, 5.0)]);
+		}
+		comb.extend([Vector2::new(20.0, 0.0), Vector2::new(20.0, -1.0), Vector2::new(0.0, -1.0)]);
+		let indices = triangulate_polygon(&comb, &[]);
+		assert_eq!(indices.len(), 3 * (comb.len() - 2));
+		assert!(fabs(triangulated_area(&comb, &indices) - fabs(area(&comb))) < 1e-3);
+	}
+
+	#[test]
+	fn holes() {
+		let mut rng = SmallRng::seed_from_u64(5);
+		for _ in 0..50 {
+			let clockwise = rng.random();
+			let outline = star(&mut rng, Vector2::new(0.0, 0.0), 9.0..10.0, 40, clockwise);
+			let holes: Vec<Vec<Vector2>> = [(-4.0, -4.0), (4.0, -4.0), (0.0, 4.0), (0.0, -4.0)].map(|(x, y)| {
+				let (count, clockwise) = (rng.random_range(3..12), rng.random());
+				star(&mut rng, Vector2::new(x, y), 0.5..1.8, count, clockwise)
+			}).into();
+			let hole_slices: Vec<&[Vector2]> = holes.iter().map(Vec::as_slice).collect();
+			let indices = triangulate_polygon(&outline, &hole_slices);
+
+			let points: Vec<Vector2> = outline.iter().chain(holes.iter().flatten()).copied().collect();
+			assert_eq!(indices.len(), 3 * (points.len() + 2 * holes.len() - 2));
+			let expected = fabs(area(&outline)) - holes.iter().map(|hole| fabs(area(hole))).sum::<f64>();
+			assert!(fabs(triangulated_area(&points, &indices) - expected) < 1e-3);
+		}
+
+		// Holes lined up on the ray from each other's rightmost vertex
+		let outline = [Vector2::new(0.0, 0.0), Vector2::new(10.0, 0.0), Vector2::new(10.0, 2.0), Vector2::new(0.0, 2.0)];
+		let square = |x: f32| [Vector2::new(x, 0.5), Vector2::new(x + 1.0, 0.5), Vector2::new(x + 1.0, 1.5), Vector2::new(x, 1.5)];
+		let (a, b, c) = (square(1.0), square(4.0), square(7.0));
+		let indices = triangulate_polygon(&outline, &[&a, &b, &c]);
+		let points: Vec<Vector2> = outline.iter().chain(&a).chain(&b).chain(&c).copied().collect();
+		assert!(fabs(triangulated_area(&points, &indices) - 17.0) < 1e-6);
+	}
+
+	#[test]
+	fn degenerate() {
+		// Collinear and repeated vertices
+		let outline = [
+			Vector2::new(0.0, 0.0),
+			Vector2::new(1.0, 0.0),
+			Vector2::new(1.0, 0.0),
+			Vector2::new(2.0, 0.0),
+			Vector2::new(2.0, 2.0),
+			Vector2::new(1.0, 2.0),
+			Vector2::new(0.0, 2.0),
+			Vector2::new(0.0, 1.0),
+		];
+		let indices = triangulate_polygon(&outline, &[]);
+		assert!(fabs(triangulated_area(&outline, &indices) - 4.0) < 1e-9);
+
+		assert!(triangulate_polygon(&outline[..2], &[]).is_empty());
+		let line = [Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 2.0)];
+		assert!(triangulate_polygon(&line, &[]).is_empty());
+
+		// A bow tie still terminates
+		let bow_tie = [Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)];
+		assert!(triangulate_polygon(&bow_tie, &[]).len() <= 6);
+	}
+}
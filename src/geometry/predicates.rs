@@ -0,0 +1,25 @@
+use crate::Vector2;
+use libm::fabs;
+
+/// Twice the signed area of `a`, `b`, `c`, positive when they turn counter-clockwise. The sign
+/// is exact while the coordinate differences fit in about 26 bits, so their products are exact in
+/// `f64`, as for coordinates of similar magnitude. Mixing `1e-3` and `1e8` can misjudge points
+/// that are nearly collinear.
+pub(super) fn orient(a: Vector2, b: Vector2, c: Vector2) -> f64 {
+	let (abx, aby) = (b.x as f64 - a.x as f64, b.y as f64 - a.y as f64);
+	let (acx, acy) = (c.x as f64 - a.x as f64, c.y as f64 - a.y as f64);
+	abx * acy - aby * acx
+}
+
+/// Positive when `d` lies inside the circumcircle of the counter-clockwise triangle `a`, `b`,
+/// `c`. Within rounding error of the circle it is zero, so cocircular points never flip back and
+/// forth.
+pub(super) fn in_circle(a: Vector2, b: Vector2, c: Vector2, d: Vector2) -> f64 {
+	let (ax, ay) = (a.x as f64 - d.x as f64, a.y as f64 - d.y as f64);
+	let (bx, by) = (b.x as f64 - d.x as f64, b.y as f64 - d.y as f64);
+	let (cx, cy) = (c.x as f64 - d.x as f64, c.y as f64 - d.y as f64);
+	let (a2, b2, c2) = (ax * ax + ay * ay, bx * bx + by * by, cx * cx + cy * cy);
+	let det = a2 * (bx * cy - by * cx) + b2 * (cx * ay - cy * ax) + c2 * (ax * by - ay * bx);
+	let permanent = a2 * (fabs(bx * cy) + fabs(by * cx)) + b2 * (fabs(cx * ay) + fabs(cy * ax)) + c2 * (fabs(ax * by) + fabs(ay * bx));
+	if fabs(det) <= 1e-12 * permanent { 0.0 } else { det }
+}
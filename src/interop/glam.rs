@@ -0,0 +1,133 @@
+use crate::{IVector2, Matrix3, Matrix4, Quaternion, Vector2, Vector3, Vector4};
+
+impl From<::glam::Vec2> for Vector2 {
+	fn from(value: ::glam::Vec2) -> Self {
+		Vector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<Vector2> for ::glam::Vec2 {
+	fn from(value: Vector2) -> Self {
+		::glam::Vec2::new(value.x, value.y)
+	}
+}
+
+impl From<::glam::Vec3> for Vector3 {
+	fn from(value: ::glam::Vec3) -> Self {
+		Vector3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<Vector3> for ::glam::Vec3 {
+	fn from(value: Vector3) -> Self {
+		::glam::Vec3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<::glam::Vec3A> for Vector3 {
+	fn from(value: ::glam::Vec3A) -> Self {
+		Vector3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<Vector3> for ::glam::Vec3A {
+	fn from(value: Vector3) -> Self {
+		::glam::Vec3A::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<::glam::Vec4> for Vector4 {
+	fn from(value: ::glam::Vec4) -> Self {
+		Vector4 { x: value.x, y: value.y, z: value.z, w: value.w }
+	}
+}
+
+impl From<Vector4> for ::glam::Vec4 {
+	fn from(value: Vector4) -> Self {
+		::glam::Vec4::new(value.x, value.y, value.z, value.w)
+	}
+}
+
+impl From<::glam::IVec2> for IVector2 {
+	fn from(value: ::glam::IVec2) -> Self {
+		IVector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<IVector2> for ::glam::IVec2 {
+	fn from(value: IVector2) -> Self {
+		::glam::IVec2::new(value.x, value.y)
+	}
+}
+
+impl From<::glam::Quat> for Quaternion {
+	fn from(value: ::glam::Quat) -> Self {
+		let [x, y, z, w] = value.to_array();
+		Quaternion { x, y, z, w }
+	}
+}
+
+impl From<Quaternion> for ::glam::Quat {
+	fn from(value: Quaternion) -> Self {
+		::glam::Quat::from_xyzw(value.x, value.y, value.z, value.w)
+	}
+}
+
+impl From<::glam::Mat3> for Matrix3 {
+	fn from(value: ::glam::Mat3) -> Self {
+		Matrix3 {
+			x: value.x_axis.into(),
+			y: value.y_axis.into(),
+			z: value.z_axis.into(),
+		}
+	}
+}
+
+impl From<Matrix3> for ::glam::Mat3 {
+	fn from(value: Matrix3) -> Self {
+		::glam::Mat3::from_cols(value.x.into(), value.y.into(), value.z.into())
+	}
+}
+
+impl From<::glam::Mat4> for Matrix4 {
+	fn from(value: ::glam::Mat4) -> Self {
+		Matrix4::from_cols_array(value.to_cols_array())
+	}
+}
+
+impl From<Matrix4> for ::glam::Mat4 {
+	fn from(value: Matrix4) -> Self {
+		::glam::Mat4::from_cols_array(&value.to_cols_array())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{IVector2, Matrix3, Matrix4, Quaternion, Vector2, Vector3, Vector4};
+
+	#[test]
+	fn round_trip() {
+		let v = Vector2 { x: 1.0, y: 2.0 };
+		assert_eq!(Vector2::from(::glam::Vec2::from(v)), v);
+		let v = Vector3::new(1.0, 2.0, 3.0);
+		assert_eq!(Vector3::from(::glam::Vec3::from(v)), v);
+		assert_eq!(Vector3::from(::glam::Vec3A::from(v)), v);
+		let v = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+		assert_eq!(::glam::Vec4::from(Vector4::from(::glam::Vec4::from(v))), ::glam::Vec4::new(1.0, 2.0, 3.0, 4.0));
+		let v = IVector2 { x: -1, y: 2 };
+		assert_eq!(IVector2::from(::glam::IVec2::from(v)), v);
+		let m = Matrix3::from_translation(Vector2 { x: 4.0, y: 5.0 });
+		assert_eq!(Matrix3::from(::glam::Mat3::from(m)), m);
+
+		let q = Quaternion::from_euler_degrees(Vector3::new(10.0, 20.0, 30.0));
+		assert_eq!(Quaternion::from(::glam::Quat::from(q)), q);
+		let m = Matrix4::from_rotation_translation_scale(q, Vector3::new(4.0, 5.0, 6.0), Vector3::ONE);
+		let glam = ::glam::Mat4::from(m);
+		assert_eq!(glam.w_axis, ::glam::Vec4::new(4.0, 5.0, 6.0, 1.0));
+		assert_eq!(Matrix4::from(glam), m);
+
+		let expected = glam.transform_point3(::glam::Vec3::X);
+		let x_axis = ::glam::Quat::from(q) * ::glam::Vec3::X + ::glam::Vec3::new(4.0, 5.0, 6.0);
+		assert!(expected.abs_diff_eq(x_axis, 1e-6));
+	}
+}
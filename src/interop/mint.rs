@@ -0,0 +1,131 @@
+use crate::{IVector2, Matrix3, Matrix4, Quaternion, Vector2, Vector3, Vector4};
+
+impl From<::mint::Vector2<f32>> for Vector2 {
+	fn from(value: ::mint::Vector2<f32>) -> Self {
+		Vector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<Vector2> for ::mint::Vector2<f32> {
+	fn from(value: Vector2) -> Self {
+		::mint::Vector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<::mint::Vector3<f32>> for Vector3 {
+	fn from(value: ::mint::Vector3<f32>) -> Self {
+		Vector3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<Vector3> for ::mint::Vector3<f32> {
+	fn from(value: Vector3) -> Self {
+		::mint::Vector3 { x: value.x, y: value.y, z: value.z }
+	}
+}
+
+impl From<::mint::Vector4<f32>> for Vector4 {
+	fn from(value: ::mint::Vector4<f32>) -> Self {
+		Vector4 { x: value.x, y: value.y, z: value.z, w: value.w }
+	}
+}
+
+impl From<Vector4> for ::mint::Vector4<f32> {
+	fn from(value: Vector4) -> Self {
+		::mint::Vector4 { x: value.x, y: value.y, z: value.z, w: value.w }
+	}
+}
+
+impl From<::mint::Vector2<i32>> for IVector2 {
+	fn from(value: ::mint::Vector2<i32>) -> Self {
+		IVector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<IVector2> for ::mint::Vector2<i32> {
+	fn from(value: IVector2) -> Self {
+		::mint::Vector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<::mint::Quaternion<f32>> for Quaternion {
+	fn from(value: ::mint::Quaternion<f32>) -> Self {
+		Quaternion { x: value.v.x, y: value.v.y, z: value.v.z, w: value.s }
+	}
+}
+
+impl From<Quaternion> for ::mint::Quaternion<f32> {
+	fn from(value: Quaternion) -> Self {
+		::mint::Quaternion {
+			v: ::mint::Vector3 { x: value.x, y: value.y, z: value.z },
+			s: value.w,
+		}
+	}
+}
+
+impl From<::mint::ColumnMatrix3<f32>> for Matrix3 {
+	fn from(value: ::mint::ColumnMatrix3<f32>) -> Self {
+		Matrix3 {
+			x: value.x.into(),
+			y: value.y.into(),
+			z: value.z.into(),
+		}
+	}
+}
+
+impl From<Matrix3> for ::mint::ColumnMatrix3<f32> {
+	fn from(value: Matrix3) -> Self {
+		::mint::ColumnMatrix3 {
+			x: value.x.into(),
+			y: value.y.into(),
+			z: value.z.into(),
+		}
+	}
+}
+
+impl From<::mint::ColumnMatrix4<f32>> for Matrix4 {
+	fn from(value: ::mint::ColumnMatrix4<f32>) -> Self {
+		let ::mint::ColumnMatrix4 { x, y, z, w } = value;
+		Matrix4 {
+			m00: x.x, m10: y.x, m20: z.x, m30: w.x,
+			m01: x.y, m11: y.y, m21: z.y, m31: w.y,
+			m02: x.z, m12: y.z, m22: z.z, m32: w.z,
+			m03: x.w, m13: y.w, m23: z.w, m33: w.w,
+		}
+	}
+}
+
+impl From<Matrix4> for ::mint::ColumnMatrix4<f32> {
+	fn from(m: Matrix4) -> Self {
+		::mint::ColumnMatrix4 {
+			x: ::mint::Vector4 { x: m.m00, y: m.m01, z: m.m02, w: m.m03 },
+			y: ::mint::Vector4 { x: m.m10, y: m.m11, z: m.m12, w: m.m13 },
+			z: ::mint::Vector4 { x: m.m20, y: m.m21, z: m.m22, w: m.m23 },
+			w: ::mint::Vector4 { x: m.m30, y: m.m31, z: m.m32, w: m.m33 },
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{IVector2, Matrix3, Matrix4, Quaternion, Vector2, Vector3};
+
+	#[test]
+	fn round_trip() {
+		let v = Vector2 { x: 1.0, y: 2.0 };
+		assert_eq!(Vector2::from(::mint::Vector2::from(v)), v);
+		let v = Vector3::new(1.0, 2.0, 3.0);
+		assert_eq!(Vector3::from(::mint::Vector3::from(v)), v);
+		let v = IVector2 { x: -1, y: 2 };
+		assert_eq!(IVector2::from(::mint::Vector2::from(v)), v);
+		let q = Quaternion::from_euler_degrees(Vector3::new(10.0, 20.0, 30.0));
+		assert_eq!(Quaternion::from(::mint::Quaternion::from(q)), q);
+		let m = Matrix3::from_translation(Vector2 { x: 4.0, y: 5.0 });
+		assert_eq!(Matrix3::from(::mint::ColumnMatrix3::from(m)), m);
+
+		let m = Matrix4::from_rotation_translation_scale(q, Vector3::new(4.0, 5.0, 6.0), Vector3::ONE);
+		let column_major: ::mint::ColumnMatrix4<f32> = m.into();
+		assert_eq!(column_major.w, ::mint::Vector4 { x: 4.0, y: 5.0, z: 6.0, w: 1.0 });
+		assert_eq!(Matrix4::from(column_major), m);
+	}
+}
@@ -0,0 +1,13 @@
+//! `From` conversions to and from other math crates, each behind a cargo feature of the same name.
+//!
+//! [`Matrix4`](crate::Matrix4) field `mXY` is column `X`, row `Y`, matching the column-major
+//! storage every supported crate uses.
+
+#[cfg(feature = "glam")]
+mod glam;
+
+#[cfg(feature = "mint")]
+mod mint;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
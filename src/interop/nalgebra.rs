@@ -0,0 +1,152 @@
+use crate::{IVector2, Matrix3, Matrix4, Quaternion, Vector2, Vector3, Vector4};
+
+impl From<::nalgebra::Vector2<f32>> for Vector2 {
+	fn from(value: ::nalgebra::Vector2<f32>) -> Self {
+		Vector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<Vector2> for ::nalgebra::Vector2<f32> {
+	fn from(value: Vector2) -> Self {
+		::nalgebra::Vector2::new(value.x, value.y)
+	}
+}
+
+impl From<::nalgebra::Vector3<f32>> for Vector3 {
+	fn from(value: ::nalgebra::Vector3<f32>) -> Self {
+		Vector3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<Vector3> for ::nalgebra::Vector3<f32> {
+	fn from(value: Vector3) -> Self {
+		::nalgebra::Vector3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<::nalgebra::Point3<f32>> for Vector3 {
+	fn from(value: ::nalgebra::Point3<f32>) -> Self {
+		Vector3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<Vector3> for ::nalgebra::Point3<f32> {
+	fn from(value: Vector3) -> Self {
+		::nalgebra::Point3::new(value.x, value.y, value.z)
+	}
+}
+
+impl From<::nalgebra::Vector4<f32>> for Vector4 {
+	fn from(value: ::nalgebra::Vector4<f32>) -> Self {
+		Vector4 { x: value.x, y: value.y, z: value.z, w: value.w }
+	}
+}
+
+impl From<Vector4> for ::nalgebra::Vector4<f32> {
+	fn from(value: Vector4) -> Self {
+		::nalgebra::Vector4::new(value.x, value.y, value.z, value.w)
+	}
+}
+
+impl From<::nalgebra::Vector2<i32>> for IVector2 {
+	fn from(value: ::nalgebra::Vector2<i32>) -> Self {
+		IVector2 { x: value.x, y: value.y }
+	}
+}
+
+impl From<IVector2> for ::nalgebra::Vector2<i32> {
+	fn from(value: IVector2) -> Self {
+		::nalgebra::Vector2::new(value.x, value.y)
+	}
+}
+
+impl From<::nalgebra::Quaternion<f32>> for Quaternion {
+	fn from(value: ::nalgebra::Quaternion<f32>) -> Self {
+		Quaternion { x: value.i, y: value.j, z: value.k, w: value.w }
+	}
+}
+
+impl From<Quaternion> for ::nalgebra::Quaternion<f32> {
+	fn from(value: Quaternion) -> Self {
+		::nalgebra::Quaternion::new(value.w, value.x, value.y, value.z)
+	}
+}
+
+impl From<::nalgebra::UnitQuaternion<f32>> for Quaternion {
+	fn from(value: ::nalgebra::UnitQuaternion<f32>) -> Self {
+		value.into_inner().into()
+	}
+}
+
+/// Renormalizes, as [`Quaternion`] does not guarantee unit length.
+impl From<Quaternion> for ::nalgebra::UnitQuaternion<f32> {
+	fn from(value: Quaternion) -> Self {
+		::nalgebra::UnitQuaternion::new_normalize(value.into())
+	}
+}
+
+impl From<::nalgebra::Matrix3<f32>> for Matrix3 {
+	fn from(value: ::nalgebra::Matrix3<f32>) -> Self {
+		Matrix3 {
+			x: Vector3::new(value.m11, value.m21, value.m31),
+			y: Vector3::new(value.m12, value.m22, value.m32),
+			z: Vector3::new(value.m13, value.m23, value.m33),
+		}
+	}
+}
+
+impl From<Matrix3> for ::nalgebra::Matrix3<f32> {
+	fn from(value: Matrix3) -> Self {
+		::nalgebra::Matrix3::from_columns(&[value.x.into(), value.y.into(), value.z.into()])
+	}
+}
+
+impl From<::nalgebra::Matrix4<f32>> for Matrix4 {
+	fn from(value: ::nalgebra::Matrix4<f32>) -> Self {
+		// nalgebra's `mRC` fields are one-based row, column.
+		Matrix4 {
+			m00: value.m11, m10: value.m12, m20: value.m13, m30: value.m14,
+			m01: value.m21, m11: value.m22, m21: value.m23, m31: value.m24,
+			m02: value.m31, m12: value.m32, m22: value.m33, m32: value.m34,
+			m03: value.m41, m13: value.m42, m23: value.m43, m33: value.m44,
+		}
+	}
+}
+
+impl From<Matrix4> for ::nalgebra::Matrix4<f32> {
+	fn from(value: Matrix4) -> Self {
+		::nalgebra::Matrix4::from_column_slice(&value.to_cols_array())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{IVector2, Matrix3, Matrix4, Quaternion, Vector2, Vector3};
+
+	#[test]
+	fn round_trip() {
+		let v = Vector2 { x: 1.0, y: 2.0 };
+		assert_eq!(Vector2::from(::nalgebra::Vector2::from(v)), v);
+		let v = Vector3::new(1.0, 2.0, 3.0);
+		assert_eq!(Vector3::from(::nalgebra::Vector3::from(v)), v);
+		assert_eq!(Vector3::from(::nalgebra::Point3::from(v)), v);
+		let v = IVector2 { x: -1, y: 2 };
+		assert_eq!(IVector2::from(::nalgebra::Vector2::from(v)), v);
+		let m = Matrix3::from_translation(Vector2 { x: 4.0, y: 5.0 });
+		let nalgebra = ::nalgebra::Matrix3::from(m);
+		assert_eq!(nalgebra[(0, 2)], 4.0);
+		assert_eq!(Matrix3::from(nalgebra), m);
+
+		let q = Quaternion::from_euler_degrees(Vector3::new(10.0, 20.0, 30.0));
+		assert_eq!(Quaternion::from(::nalgebra::Quaternion::from(q)), q);
+		let m = Matrix4::from_rotation_translation_scale(q, Vector3::new(4.0, 5.0, 6.0), Vector3::ONE);
+		let nalgebra = ::nalgebra::Matrix4::from(m);
+		assert_eq!(nalgebra[(1, 3)], 5.0);
+		assert_eq!(Matrix4::from(nalgebra), m);
+
+		let rotation = ::nalgebra::UnitQuaternion::from(q);
+		let rotated = rotation * ::nalgebra::Vector3::x();
+		let column = nalgebra.fixed_view::<3, 1>(0, 0);
+		assert!((rotated - column).norm() < 1e-6);
+	}
+}
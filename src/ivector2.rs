@@ -1,25 +1,179 @@
+use crate::vector::impl_vector;
 use crate::Vector2;
 use core::hash::{Hash, Hasher};
-use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
 
 #[repr(C, align(8))]
 #[derive(Clone, Copy, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct IVector2 {
 	pub x: i32,
 	pub y: i32,
 }
 
 impl IVector2 {
-	pub const ZERO: Self = IVector2::splat(0);
-	pub const ONE: Self = IVector2::splat(1);
 	pub const UP: Self = IVector2 { x: 0, y: 1 };
 	pub const DOWN: Self = IVector2 { x: 0, y: -1 };
 	pub const LEFT: Self = IVector2 { x: 1, y: 0 };
 	pub const RIGHT: Self = IVector2 { x: -1, y: 0 };
 
-	pub const fn splat(value: i32) -> Self {
-		IVector2 { x: value, y: value }
+	pub const fn new(x: i32, y: i32) -> Self {
+		IVector2 { x, y }
+	}
+
+	/// Sum of the absolute differences, the number of orthogonal steps between two tiles.
+	pub const fn manhattan_distance(&self, other: IVector2) -> u64 {
+		self.x.abs_diff(other.x) as u64 + self.y.abs_diff(other.y) as u64
+	}
+
+	/// Largest absolute difference, the number of steps between two tiles when diagonal moves are allowed.
+	pub const fn chebyshev_distance(&self, other: IVector2) -> u32 {
+		let dx = self.x.abs_diff(other.x);
+		let dy = self.y.abs_diff(other.y);
+		if dx > dy { dx } else { dy }
+	}
+
+	/// Iterates the four orthogonally adjacent tiles.
+	pub fn neighbours4(self) -> impl Iterator<Item = IVector2> {
+		const OFFSETS: [IVector2; 4] = [
+			IVector2 { x: 0, y: 1 },
+			IVector2 { x: 1, y: 0 },
+			IVector2 { x: 0, y: -1 },
+			IVector2 { x: -1, y: 0 },
+		];
+		OFFSETS.into_iter().map(move |offset| self + offset)
+	}
+
+	/// Iterates the eight orthogonally and diagonally adjacent tiles.
+	pub fn neighbours8(self) -> impl Iterator<Item = IVector2> {
+		const OFFSETS: [IVector2; 8] = [
+			IVector2 { x: 0, y: 1 },
+			IVector2 { x: 1, y: 1 },
+			IVector2 { x: 1, y: 0 },
+			IVector2 { x: 1, y: -1 },
+			IVector2 { x: 0, y: -1 },
+			IVector2 { x: -1, y: -1 },
+			IVector2 { x: -1, y: 0 },
+			IVector2 { x: -1, y: 1 },
+		];
+		OFFSETS.into_iter().map(move |offset| self + offset)
+	}
+
+	pub const fn abs(&self) -> IVector2 {
+		IVector2 { x: self.x.abs(), y: self.y.abs() }
+	}
+
+	pub const fn signum(&self) -> IVector2 {
+		IVector2 { x: self.x.signum(), y: self.y.signum() }
+	}
+
+	pub const fn min(&self, other: IVector2) -> IVector2 {
+		IVector2 {
+			x: if self.x < other.x { self.x } else { other.x },
+			y: if self.y < other.y { self.y } else { other.y },
+		}
+	}
+
+	pub const fn max(&self, other: IVector2) -> IVector2 {
+		IVector2 {
+			x: if self.x > other.x { self.x } else { other.x },
+			y: if self.y > other.y { self.y } else { other.y },
+		}
+	}
+
+	/// Clamps each component between the matching components of `min` and `max`.
+	pub const fn clamp(&self, min: IVector2, max: IVector2) -> IVector2 {
+		self.max(min).min(max)
+	}
+
+	/// Rotates 90 degrees counter-clockwise, so `UP` becomes `(-1, 0)`.
+	pub const fn rotate_ccw(&self) -> IVector2 {
+		IVector2 { x: -self.y, y: self.x }
+	}
+
+	/// Rotates 90 degrees clockwise, so `UP` becomes `(1, 0)`.
+	pub const fn rotate_cw(&self) -> IVector2 {
+		IVector2 { x: self.y, y: -self.x }
+	}
+
+	pub const fn rotate_180(&self) -> IVector2 {
+		IVector2 { x: -self.x, y: -self.y }
+	}
+
+	/// Euclidean division, rounding toward negative infinity for positive divisors so
+	/// negative coordinates land in the correct cell.
+	pub const fn div_euclid(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.div_euclid(rhs.x), y: self.y.div_euclid(rhs.y) }
+	}
+
+	/// Euclidean remainder, always in `0..rhs` for positive divisors.
+	pub const fn rem_euclid(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.rem_euclid(rhs.x), y: self.y.rem_euclid(rhs.y) }
+	}
+
+	/// Splits a tile coordinate into the coordinate of the chunk containing it and the
+	/// tile's offset within that chunk. `chunk_size` must be positive.
+	pub const fn split_chunk(&self, chunk_size: IVector2) -> (IVector2, IVector2) {
+		(self.div_euclid(chunk_size), self.rem_euclid(chunk_size))
+	}
+
+	/// Inverse of [`IVector2::split_chunk`].
+	pub const fn from_chunk(chunk: IVector2, local: IVector2, chunk_size: IVector2) -> IVector2 {
+		IVector2 {
+			x: (chunk.x * chunk_size.x) + local.x,
+			y: (chunk.y * chunk_size.y) + local.y,
+		}
+	}
+
+	pub const fn checked_add(&self, rhs: IVector2) -> Option<IVector2> {
+		match (self.x.checked_add(rhs.x), self.y.checked_add(rhs.y)) {
+			(Some(x), Some(y)) => Some(IVector2 { x, y }),
+			_ => None,
+		}
+	}
+
+	pub const fn checked_sub(&self, rhs: IVector2) -> Option<IVector2> {
+		match (self.x.checked_sub(rhs.x), self.y.checked_sub(rhs.y)) {
+			(Some(x), Some(y)) => Some(IVector2 { x, y }),
+			_ => None,
+		}
+	}
+
+	pub const fn checked_mul(&self, rhs: IVector2) -> Option<IVector2> {
+		match (self.x.checked_mul(rhs.x), self.y.checked_mul(rhs.y)) {
+			(Some(x), Some(y)) => Some(IVector2 { x, y }),
+			_ => None,
+		}
+	}
+
+	pub const fn checked_div(&self, rhs: IVector2) -> Option<IVector2> {
+		match (self.x.checked_div(rhs.x), self.y.checked_div(rhs.y)) {
+			(Some(x), Some(y)) => Some(IVector2 { x, y }),
+			_ => None,
+		}
+	}
+
+	pub const fn wrapping_add(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.wrapping_add(rhs.x), y: self.y.wrapping_add(rhs.y) }
+	}
+
+	pub const fn wrapping_sub(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.wrapping_sub(rhs.x), y: self.y.wrapping_sub(rhs.y) }
+	}
+
+	pub const fn wrapping_mul(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.wrapping_mul(rhs.x), y: self.y.wrapping_mul(rhs.y) }
+	}
+
+	pub const fn saturating_add(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.saturating_add(rhs.x), y: self.y.saturating_add(rhs.y) }
+	}
+
+	pub const fn saturating_sub(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.saturating_sub(rhs.x), y: self.y.saturating_sub(rhs.y) }
+	}
+
+	pub const fn saturating_mul(&self, rhs: IVector2) -> IVector2 {
+		IVector2 { x: self.x.saturating_mul(rhs.x), y: self.y.saturating_mul(rhs.y) }
 	}
 }
 
@@ -30,6 +184,7 @@ impl PartialEq for IVector2 {
 }
 impl Eq for IVector2 {}
 
+/// Truncates toward zero, see [`Vector2::floor_to_ivec`] for tile lookups.
 impl From<Vector2> for IVector2 {
     fn from(value: Vector2) -> Self {
 		IVector2 {
@@ -50,73 +205,7 @@ impl Hash for IVector2 {
     }
 }
 
-impl Add<IVector2> for IVector2 {
-    type Output = IVector2;
-    fn add(self, rhs: IVector2) -> Self::Output {
-		IVector2 {
-			x: self.x + rhs.x,
-			y: self.y + rhs.y,
-		}
-    }
-}
-
-impl AddAssign<IVector2> for IVector2 {
-    fn add_assign(&mut self, rhs: IVector2) {
-		self.x += rhs.x;
-		self.y += rhs.y;
-    }
-}
-
-impl Sub<IVector2> for IVector2 {
-    type Output = IVector2;
-    fn sub(self, rhs: IVector2) -> Self::Output {
-		IVector2 {
-			x: self.x - rhs.x,
-			y: self.y - rhs.y,
-		}
-    }
-}
-
-impl SubAssign<IVector2> for IVector2 {
-    fn sub_assign(&mut self, rhs: IVector2) {
-		self.x -= rhs.x;
-		self.y -= rhs.y;
-    }
-}
-
-impl Mul<IVector2> for IVector2 {
-    type Output = IVector2;
-    fn mul(self, rhs: IVector2) -> Self::Output {
-		IVector2 {
-			x: self.x * rhs.x,
-			y: self.y * rhs.y,
-		}
-    }
-}
-
-impl MulAssign<IVector2> for IVector2 {
-    fn mul_assign(&mut self, rhs: IVector2) {
-		self.x *= rhs.x;
-		self.y *= rhs.y;
-    }
-}
-
-impl Div<IVector2> for IVector2 {
-    type Output = IVector2;
-    fn div(self, rhs: IVector2) -> Self::Output {
-		IVector2 {
-			x: self.x / rhs.x,
-			y: self.y / rhs.y,
-		}
-    }
-}
-
-impl DivAssign<IVector2> for IVector2 {
-    fn div_assign(&mut self, rhs: IVector2) {
-		self.x /= rhs.x;
-		self.y /= rhs.y;
-    }
-}
+impl_vector!(IVector2, i32, 0, 1, [x, y]);
 
 // #[cfg(test)]
 // mod ivector2_tests{
@@ -145,4 +234,51 @@ impl DivAssign<IVector2> for IVector2 {
 // 		assert_ne!(zero_hash, one_hash);
 // 		assert_eq!(zero_hash, zero_two_hash);
 // 	}
-// }
\ No newline at end of file
+// }
+
+#[cfg(test)]
+mod tests {
+	use crate::IVector2;
+
+	#[test]
+	fn grid() {
+		let a = IVector2 { x: 2, y: -3 };
+		let b = IVector2 { x: -1, y: 1 };
+		assert_eq!(a.manhattan_distance(b), 7);
+		assert_eq!(a.chebyshev_distance(b), 4);
+		assert_eq!(a.dot(b), -5);
+		assert_eq!(a.abs(), IVector2 { x: 2, y: 3 });
+		assert_eq!(a.signum(), IVector2 { x: 1, y: -1 });
+		assert_eq!(a.clamp(IVector2::splat(-2), IVector2::splat(1)), IVector2 { x: 1, y: -2 });
+		assert_eq!(a.rotate_ccw().rotate_cw(), a);
+		assert_eq!(IVector2::UP.rotate_cw(), IVector2 { x: 1, y: 0 });
+		assert_eq!(a * 2, IVector2 { x: 4, y: -6 });
+		assert_eq!(a / 2, IVector2 { x: 1, y: -1 });
+		assert_eq!(IVector2::ZERO.neighbours4().count(), 4);
+		assert!(IVector2::ZERO.neighbours8().all(|n| n.chebyshev_distance(IVector2::ZERO) == 1));
+	}
+
+	#[test]
+	fn overflow() {
+		let max = IVector2::splat(i32::MAX);
+		assert_eq!(max.checked_add(IVector2::ONE), None);
+		assert_eq!(max.checked_sub(IVector2::ONE), Some(IVector2::splat(i32::MAX - 1)));
+		assert_eq!(max.checked_div(IVector2::ZERO), None);
+		assert_eq!(max.wrapping_add(IVector2::ONE), IVector2::splat(i32::MIN));
+		assert_eq!(max.saturating_mul(IVector2::splat(2)), max);
+
+		let min = IVector2::splat(i32::MIN);
+		assert_eq!(min.manhattan_distance(max), 2 * u32::MAX as u64);
+		assert_eq!(min.chebyshev_distance(max), u32::MAX);
+	}
+
+	#[test]
+	fn chunks() {
+		let size = IVector2::splat(16);
+		let tile = IVector2 { x: -1, y: 17 };
+		let (chunk, local) = tile.split_chunk(size);
+		assert_eq!(chunk, IVector2 { x: -1, y: 1 });
+		assert_eq!(local, IVector2 { x: 15, y: 1 });
+		assert_eq!(IVector2::from_chunk(chunk, local, size), tile);
+	}
+}
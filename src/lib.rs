@@ -2,18 +2,78 @@
 //! Bespoke vector math library
 //!
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod aabb;
+pub use aabb::Aabb;
+
+mod angle;
+pub use angle::{Degrees, Radians};
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
+mod batch;
+
+pub mod collision;
+
+#[cfg(feature = "deterministic")]
+mod deterministic;
+
+mod fixed;
+pub use fixed::{Fixed32, Fixed64, FixedPoint, FixedQuaternion, FixedVector2, FixedVector3};
+
+mod format;
+pub use format::ParseError;
+
+#[cfg(any(feature = "rand", feature = "arbitrary", feature = "proptest"))]
+mod generate;
+
+#[cfg(feature = "alloc")]
+pub mod geometry;
+
+mod interop;
+
 mod ivector2;
 pub use ivector2::IVector2;
 
+mod linear_rgba;
+pub use linear_rgba::LinearRgba;
+
 mod matrix3;
 pub use matrix3::Matrix3;
 
 mod matrix4;
 pub use matrix4::Matrix4;
 
+pub mod packing;
+
+mod plane;
+pub use plane::Plane;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
 mod quaternion;
 pub use quaternion::Quaternion;
 
+#[cfg(feature = "rand")]
+pub mod random;
+
+mod scalar;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+mod srgba;
+pub use srgba::{linear_to_srgb, srgb_to_linear, HexParseError, Srgba};
+
+mod transform;
+pub use transform::Transform;
+
+mod vector;
+
 mod vector2;
 pub use vector2::Vector2;
 
@@ -21,4 +81,7 @@ mod vector3;
 pub use vector3::Vector3;
 
 mod vector4;
-pub use vector4::Vector4;
\ No newline at end of file
+pub use vector4::Vector4;
+
+mod wide;
+pub use wide::{Mask4, Mask8, Vector3x4, Vector3x8};
@@ -0,0 +1,221 @@
+use crate::srgba::linear_to_srgb;
+use crate::{Srgba, Vector3, Vector4};
+use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign};
+
+/// A color in linear space with straight (non-premultiplied) alpha.
+/// Lighting, blending and interpolation should all be done with this type.
+/// This struct is [repr(C)] with an alignment of 16 to satisfy std140
+#[repr(C, align(16))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct LinearRgba {
+	pub r: f32,
+	pub g: f32,
+	pub b: f32,
+	pub a: f32,
+}
+
+impl LinearRgba {
+	pub const TRANSPARENT: Self = LinearRgba::rgba(0.0, 0.0, 0.0, 0.0);
+	pub const BLACK: Self = LinearRgba::rgb(0.0, 0.0, 0.0);
+	pub const WHITE: Self = LinearRgba::rgb(1.0, 1.0, 1.0);
+	pub const RED: Self = LinearRgba::rgb(1.0, 0.0, 0.0);
+	pub const GREEN: Self = LinearRgba::rgb(0.0, 1.0, 0.0);
+	pub const BLUE: Self = LinearRgba::rgb(0.0, 0.0, 1.0);
+	pub const YELLOW: Self = LinearRgba::rgb(1.0, 1.0, 0.0);
+	pub const CYAN: Self = LinearRgba::rgb(0.0, 1.0, 1.0);
+	pub const MAGENTA: Self = LinearRgba::rgb(1.0, 0.0, 1.0);
+
+	pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+		LinearRgba { r, g, b, a: 1.0 }
+	}
+
+	pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+		LinearRgba { r, g, b, a }
+	}
+
+	pub const fn with_alpha(self, a: f32) -> Self {
+		LinearRgba { a, ..self }
+	}
+
+	/// Encodes into gamma space, alpha is passed through unchanged.
+	pub fn to_srgb(&self) -> Srgba {
+		Srgba {
+			r: linear_to_srgb(self.r),
+			g: linear_to_srgb(self.g),
+			b: linear_to_srgb(self.b),
+			a: self.a,
+		}
+	}
+
+	/// Multiplies the color channels by alpha.
+	pub fn premultiply(&self) -> Self {
+		LinearRgba {
+			r: self.r * self.a,
+			g: self.g * self.a,
+			b: self.b * self.a,
+			a: self.a,
+		}
+	}
+
+	/// Inverse of [`LinearRgba::premultiply`], fully transparent colors become [`LinearRgba::TRANSPARENT`].
+	pub fn unpremultiply(&self) -> Self {
+		if self.a == 0.0 {
+			return LinearRgba::TRANSPARENT;
+		}
+		LinearRgba {
+			r: self.r / self.a,
+			g: self.g / self.a,
+			b: self.b / self.a,
+			a: self.a,
+		}
+	}
+
+	/// Composites `self` over `background` using the porter-duff "over" operator.
+	pub fn over(&self, background: LinearRgba) -> Self {
+		let src = self.premultiply();
+		let dst = background.premultiply();
+		(src + (dst * (1.0 - src.a))).unpremultiply()
+	}
+
+	/// Linearly interpolates every channel, including alpha.
+	pub fn lerp(&self, other: LinearRgba, t: f32) -> Self {
+		*self + ((other - *self) * t)
+	}
+
+	/// Relative luminance as defined by Rec. 709.
+	pub fn luminance(&self) -> f32 {
+		(0.2126 * self.r) + (0.7152 * self.g) + (0.0722 * self.b)
+	}
+}
+
+impl From<Srgba> for LinearRgba {
+	fn from(value: Srgba) -> Self {
+		value.to_linear()
+	}
+}
+
+impl From<Vector4> for LinearRgba {
+	fn from(value: Vector4) -> Self {
+		LinearRgba { r: value.x, g: value.y, b: value.z, a: value.w }
+	}
+}
+
+impl From<LinearRgba> for Vector4 {
+	fn from(value: LinearRgba) -> Self {
+		Vector4 { x: value.r, y: value.g, z: value.b, w: value.a }
+	}
+}
+
+/// Opaque color from `x`, `y`, `z` as red, green and blue.
+impl From<Vector3> for LinearRgba {
+	fn from(value: Vector3) -> Self {
+		LinearRgba::rgb(value.x, value.y, value.z)
+	}
+}
+
+/// Drops the alpha channel.
+impl From<LinearRgba> for Vector3 {
+	fn from(value: LinearRgba) -> Self {
+		Vector3::new(value.r, value.g, value.b)
+	}
+}
+
+// F32 OPS
+impl Mul<f32> for LinearRgba {
+	type Output = LinearRgba;
+	fn mul(self, rhs: f32) -> Self::Output {
+		LinearRgba {
+			r: self.r * rhs,
+			g: self.g * rhs,
+			b: self.b * rhs,
+			a: self.a * rhs,
+		}
+	}
+}
+impl MulAssign<f32> for LinearRgba {
+	fn mul_assign(&mut self, rhs: f32) {
+		self.r *= rhs;
+		self.g *= rhs;
+		self.b *= rhs;
+		self.a *= rhs;
+	}
+}
+
+// LINEARRGBA OPS
+impl Add<LinearRgba> for LinearRgba {
+	type Output = LinearRgba;
+	fn add(self, rhs: LinearRgba) -> Self::Output {
+		LinearRgba {
+			r: self.r + rhs.r,
+			g: self.g + rhs.g,
+			b: self.b + rhs.b,
+			a: self.a + rhs.a,
+		}
+	}
+}
+impl AddAssign<LinearRgba> for LinearRgba {
+	fn add_assign(&mut self, rhs: LinearRgba) {
+		self.r += rhs.r;
+		self.g += rhs.g;
+		self.b += rhs.b;
+		self.a += rhs.a;
+	}
+}
+
+impl Sub<LinearRgba> for LinearRgba {
+	type Output = LinearRgba;
+	fn sub(self, rhs: LinearRgba) -> Self::Output {
+		LinearRgba {
+			r: self.r - rhs.r,
+			g: self.g - rhs.g,
+			b: self.b - rhs.b,
+			a: self.a - rhs.a,
+		}
+	}
+}
+impl SubAssign<LinearRgba> for LinearRgba {
+	fn sub_assign(&mut self, rhs: LinearRgba) {
+		self.r -= rhs.r;
+		self.g -= rhs.g;
+		self.b -= rhs.b;
+		self.a -= rhs.a;
+	}
+}
+
+/// Modulates one color by another, as when tinting a texture.
+impl Mul<LinearRgba> for LinearRgba {
+	type Output = LinearRgba;
+	fn mul(self, rhs: LinearRgba) -> Self::Output {
+		LinearRgba {
+			r: self.r * rhs.r,
+			g: self.g * rhs.g,
+			b: self.b * rhs.b,
+			a: self.a * rhs.a,
+		}
+	}
+}
+impl MulAssign<LinearRgba> for LinearRgba {
+	fn mul_assign(&mut self, rhs: LinearRgba) {
+		self.r *= rhs.r;
+		self.g *= rhs.g;
+		self.b *= rhs.b;
+		self.a *= rhs.a;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{LinearRgba, Vector4};
+
+	#[test]
+	fn blending() {
+		let half_red = LinearRgba::RED.with_alpha(0.5);
+		assert_eq!(half_red.premultiply(), LinearRgba::rgba(0.5, 0.0, 0.0, 0.5));
+		assert_eq!(half_red.premultiply().unpremultiply(), half_red);
+		assert_eq!(half_red.over(LinearRgba::BLUE), LinearRgba::rgba(0.5, 0.0, 0.5, 1.0));
+		assert_eq!(LinearRgba::TRANSPARENT.over(LinearRgba::GREEN), LinearRgba::GREEN);
+		assert_eq!(LinearRgba::BLACK.lerp(LinearRgba::WHITE, 0.25), LinearRgba::rgb(0.25, 0.25, 0.25));
+		assert_eq!(LinearRgba::from(Vector4::from(half_red)), half_red);
+	}
+}
@@ -1,8 +1,12 @@
 use crate::{Vector2, Vector3};
+use core::ops::{Index, IndexMut};
 
+/// A single precision 3x3 matrix stored as three [`Vector3`] columns `x`, `y` and `z`.
+/// Every array and slice conversion is column-major, `[x.x, x.y, x.z, y.x, ..]`, though in
+/// memory each column is followed by the [`Vector3`] padding as in GLSL's std140 `mat3`.
 #[repr(C, align(16))]
 #[derive(Clone, Copy, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Matrix3 {
 	pub x: Vector3,
 	pub y: Vector3,
@@ -11,24 +15,176 @@ pub struct Matrix3 {
 
 impl Matrix3 {
 	pub const IDENTITY: Self = Matrix3 {
-		x: Vector3 { x: 1.0, y: 0.0, z: 0.0 },
-		y: Vector3 { x: 0.0, y: 1.0, z: 0.0 },
-		z: Vector3 { x: 0.0, y: 0.0, z: 1.0 },
+		x: Vector3::new(1.0, 0.0, 0.0),
+		y: Vector3::new(0.0, 1.0, 0.0),
+		z: Vector3::new(0.0, 0.0, 1.0),
 	};
 
-	pub fn from_translation(translation: Vector2) -> Self {
+	pub const fn from_cols(x: Vector3, y: Vector3, z: Vector3) -> Self {
+		Matrix3 { x, y, z }
+	}
+
+	pub const fn from_rows(x: Vector3, y: Vector3, z: Vector3) -> Self {
+		Matrix3 {
+			x: Vector3::new(x.x, y.x, z.x),
+			y: Vector3::new(x.y, y.y, z.y),
+			z: Vector3::new(x.z, y.z, z.z),
+		}
+	}
+
+	pub const fn from_cols_array(m: [f32; 9]) -> Self {
 		Matrix3 {
-			x: Vector3 { x: 1.0, y: 0.0, z: 0.0 },
-			y: Vector3 { x: 0.0, y: 1.0, z: 0.0 },
-			z: Vector3 { x: translation.x, y: translation.y, z: 1.0 },
+			x: Vector3::new(m[0], m[1], m[2]),
+			y: Vector3::new(m[3], m[4], m[5]),
+			z: Vector3::new(m[6], m[7], m[8]),
 		}
 	}
 
-	pub fn ortho(width: f32, height: f32) -> Self {
+	pub const fn from_cols_array_2d(m: [[f32; 3]; 3]) -> Self {
 		Matrix3 {
-			x: Vector3 { x: (2.0 / width), y: 0.0, z: 0.0 },
-			y: Vector3 { x: 0.0, y: (2.0 / height), z: 0.0 },
-			z: Vector3 { x: 0.0, y: 0.0, z: 1.0 },
+			x: Vector3::new(m[0][0], m[0][1], m[0][2]),
+			y: Vector3::new(m[1][0], m[1][1], m[1][2]),
+			z: Vector3::new(m[2][0], m[2][1], m[2][2]),
+		}
+	}
+
+	/// Reads the first 9 values of `slice` in column-major order, panics if it is shorter.
+	pub fn from_cols_slice(slice: &[f32]) -> Self {
+		let mut m = [0.0; 9];
+		m.copy_from_slice(&slice[..9]);
+		Matrix3::from_cols_array(m)
+	}
+
+	pub const fn to_cols_array(&self) -> [f32; 9] {
+		[
+			self.x.x, self.x.y, self.x.z,
+			self.y.x, self.y.y, self.y.z,
+			self.z.x, self.z.y, self.z.z,
+		]
+	}
+
+	pub const fn to_cols_array_2d(&self) -> [[f32; 3]; 3] {
+		[
+			[self.x.x, self.x.y, self.x.z],
+			[self.y.x, self.y.y, self.y.z],
+			[self.z.x, self.z.y, self.z.z],
+		]
+	}
+
+	/// Column `index`, panics if `index > 2`.
+	pub const fn col(&self, index: usize) -> Vector3 {
+		match index {
+			0 => self.x,
+			1 => self.y,
+			2 => self.z,
+			_ => panic!("Matrix3 column index out of range"),
 		}
 	}
+
+	/// Row `index`, panics if `index > 2`.
+	pub const fn row(&self, index: usize) -> Vector3 {
+		match index {
+			0 => Vector3::new(self.x.x, self.y.x, self.z.x),
+			1 => Vector3::new(self.x.y, self.y.y, self.z.y),
+			2 => Vector3::new(self.x.z, self.y.z, self.z.z),
+			_ => panic!("Matrix3 row index out of range"),
+		}
+	}
+
+	/// Replaces column `index`, panics if `index > 2`.
+	pub fn set_col(&mut self, index: usize, col: Vector3) {
+		self[index] = col;
+	}
+
+	pub const fn from_translation(translation: Vector2) -> Self {
+		Matrix3 {
+			x: Vector3::new(1.0, 0.0, 0.0),
+			y: Vector3::new(0.0, 1.0, 0.0),
+			z: Vector3::new(translation.x, translation.y, 1.0),
+		}
+	}
+
+	pub const fn from_scale(scale: Vector2) -> Self {
+		Matrix3 {
+			x: Vector3::new(scale.x, 0.0, 0.0),
+			y: Vector3::new(0.0, scale.y, 0.0),
+			z: Vector3::new(0.0, 0.0, 1.0),
+		}
+	}
+
+	pub const fn ortho(width: f32, height: f32) -> Self {
+		Matrix3 {
+			x: Vector3::new(2.0 / width, 0.0, 0.0),
+			y: Vector3::new(0.0, 2.0 / height, 0.0),
+			z: Vector3::new(0.0, 0.0, 1.0),
+		}
+	}
+}
+
+/// Column `index`, panics if `index > 2`.
+impl Index<usize> for Matrix3 {
+	type Output = Vector3;
+	fn index(&self, index: usize) -> &Vector3 {
+		match index {
+			0 => &self.x,
+			1 => &self.y,
+			2 => &self.z,
+			_ => panic!("Matrix3 column index out of range"),
+		}
+	}
+}
+
+impl IndexMut<usize> for Matrix3 {
+	fn index_mut(&mut self, index: usize) -> &mut Vector3 {
+		match index {
+			0 => &mut self.x,
+			1 => &mut self.y,
+			2 => &mut self.z,
+			_ => panic!("Matrix3 column index out of range"),
+		}
+	}
+}
+
+/// Columns in the same order as [`Matrix3::from_cols_array_2d`].
+impl From<[[f32; 3]; 3]> for Matrix3 {
+	fn from(value: [[f32; 3]; 3]) -> Self {
+		Matrix3::from_cols_array_2d(value)
+	}
+}
+
+impl From<Matrix3> for [[f32; 3]; 3] {
+	fn from(value: Matrix3) -> Self {
+		value.to_cols_array_2d()
+	}
+}
+
+impl From<[f32; 9]> for Matrix3 {
+	fn from(value: [f32; 9]) -> Self {
+		Matrix3::from_cols_array(value)
+	}
+}
+
+impl From<Matrix3> for [f32; 9] {
+	fn from(value: Matrix3) -> Self {
+		value.to_cols_array()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Matrix3, Vector2, Vector3};
+
+	#[test]
+	fn columns_and_rows() {
+		let mut basis = Matrix3::from_translation(Vector2::new(7.0, 8.0));
+		assert_eq!(basis[2], Vector3::new(7.0, 8.0, 1.0));
+		assert_eq!(basis.row(0), Vector3::new(1.0, 0.0, 7.0));
+		assert_eq!(Matrix3::from_rows(basis.row(0), basis.row(1), basis.row(2)), basis);
+		assert_eq!(Matrix3::from(basis.to_cols_array_2d()), basis);
+		assert_eq!(Matrix3::from_cols_slice(&basis.to_cols_array()), basis);
+
+		basis.set_col(2, Vector3::new(0.0, 0.0, 1.0));
+		basis[0].x = 2.0;
+		assert_eq!(basis, Matrix3::from_scale(Vector2::new(2.0, 1.0)));
+	}
 }
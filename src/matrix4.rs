@@ -1,11 +1,16 @@
-use crate::{Quaternion, Vector3};
-use core::ops::Mul;
-use libm::tanf;
+use crate::batch;
+use crate::{Quaternion, Radians, Vector3, Vector4};
+use core::ops::{Index, IndexMut, Mul};
+use libm::{sqrtf, tanf};
 
+/// A single precision 4x4 matrix stored column-major, `mCR` is column `C`, row `R`.
+/// Memory order is `m00, m01, m02, m03` (the first column) through `m30, m31, m32, m33`
+/// (the last column, which holds the translation), matching GLSL's `mat4`.
+/// Every array and slice conversion uses this same column-major order.
 #[repr(C)]
 #[repr(align(16))]
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Matrix4 {
     pub m00: f32,
     pub m01: f32,
@@ -33,7 +38,110 @@ impl Matrix4 {
         m03: 0.0, m13: 0.0, m23: 0.0, m33: 1.0,
     };
     
-    pub fn from_perspective(fov: f32, aspect: f32, clip_near: f32, clip_far: f32) -> Matrix4 {
+    pub const fn from_cols(x: Vector4, y: Vector4, z: Vector4, w: Vector4) -> Matrix4 {
+        Matrix4 {
+            m00: x.x, m10: y.x, m20: z.x, m30: w.x,
+            m01: x.y, m11: y.y, m21: z.y, m31: w.y,
+            m02: x.z, m12: y.z, m22: z.z, m32: w.z,
+            m03: x.w, m13: y.w, m23: z.w, m33: w.w,
+        }
+    }
+
+    pub const fn from_rows(x: Vector4, y: Vector4, z: Vector4, w: Vector4) -> Matrix4 {
+        Matrix4::from_cols(x, y, z, w).transpose()
+    }
+
+    pub const fn from_cols_array(m: [f32; 16]) -> Matrix4 {
+        Matrix4 {
+            m00: m[0], m01: m[1], m02: m[2], m03: m[3],
+            m10: m[4], m11: m[5], m12: m[6], m13: m[7],
+            m20: m[8], m21: m[9], m22: m[10], m23: m[11],
+            m30: m[12], m31: m[13], m32: m[14], m33: m[15],
+        }
+    }
+
+    pub const fn from_cols_array_2d(m: [[f32; 4]; 4]) -> Matrix4 {
+        Matrix4::from_cols(
+            Vector4::new(m[0][0], m[0][1], m[0][2], m[0][3]),
+            Vector4::new(m[1][0], m[1][1], m[1][2], m[1][3]),
+            Vector4::new(m[2][0], m[2][1], m[2][2], m[2][3]),
+            Vector4::new(m[3][0], m[3][1], m[3][2], m[3][3]),
+        )
+    }
+
+    /// Reads the first 16 values of `slice` in column-major order, panics if it is shorter.
+    pub fn from_cols_slice(slice: &[f32]) -> Matrix4 {
+        let mut m = [0.0; 16];
+        m.copy_from_slice(&slice[..16]);
+        Matrix4::from_cols_array(m)
+    }
+
+    pub const fn to_cols_array(&self) -> [f32; 16] {
+        [
+            self.m00, self.m01, self.m02, self.m03,
+            self.m10, self.m11, self.m12, self.m13,
+            self.m20, self.m21, self.m22, self.m23,
+            self.m30, self.m31, self.m32, self.m33,
+        ]
+    }
+
+    pub const fn to_cols_array_2d(&self) -> [[f32; 4]; 4] {
+        [
+            [self.m00, self.m01, self.m02, self.m03],
+            [self.m10, self.m11, self.m12, self.m13],
+            [self.m20, self.m21, self.m22, self.m23],
+            [self.m30, self.m31, self.m32, self.m33],
+        ]
+    }
+
+    /// Column `index`, panics if `index > 3`.
+    pub const fn col(&self, index: usize) -> Vector4 {
+        match index {
+            0 => Vector4::new(self.m00, self.m01, self.m02, self.m03),
+            1 => Vector4::new(self.m10, self.m11, self.m12, self.m13),
+            2 => Vector4::new(self.m20, self.m21, self.m22, self.m23),
+            3 => Vector4::new(self.m30, self.m31, self.m32, self.m33),
+            _ => panic!("Matrix4 column index out of range"),
+        }
+    }
+
+    /// Row `index`, panics if `index > 3`.
+    pub const fn row(&self, index: usize) -> Vector4 {
+        match index {
+            0 => Vector4::new(self.m00, self.m10, self.m20, self.m30),
+            1 => Vector4::new(self.m01, self.m11, self.m21, self.m31),
+            2 => Vector4::new(self.m02, self.m12, self.m22, self.m32),
+            3 => Vector4::new(self.m03, self.m13, self.m23, self.m33),
+            _ => panic!("Matrix4 row index out of range"),
+        }
+    }
+
+    /// Replaces column `index`, panics if `index > 3`.
+    pub fn set_col(&mut self, index: usize, col: Vector4) {
+        self[index] = col;
+    }
+
+    pub const fn from_translation(translation: Vector3) -> Matrix4 {
+        Matrix4 {
+            m30: translation.x, m31: translation.y, m32: translation.z,
+            ..Matrix4::IDENTITY
+        }
+    }
+
+    pub const fn from_scale(scale: Vector3) -> Matrix4 {
+        Matrix4 {
+            m00: scale.x, m11: scale.y, m22: scale.z,
+            ..Matrix4::IDENTITY
+        }
+    }
+
+    /// Perspective projection with a vertical field of view of `fov`.
+    pub fn from_perspective(fov: impl Into<Radians>, aspect: f32, clip_near: f32, clip_far: f32) -> Matrix4 {
+        Matrix4::from_perspective_radians(fov.into().0, aspect, clip_near, clip_far)
+    }
+
+    /// Perspective projection with a vertical field of view of `fov` radians.
+    pub fn from_perspective_radians(fov: f32, aspect: f32, clip_near: f32, clip_far: f32) -> Matrix4 {
         let f = 1.0 / tanf(fov / 2.0);
         let fa = f / aspect;
         let nf = 1.0 / (clip_near - clip_far);
@@ -46,7 +154,7 @@ impl Matrix4 {
         }
     }
 
-    pub fn from_rotation_translation_scale(rotation: Quaternion, translation: Vector3, scale: Vector3) -> Matrix4 {
+    pub const fn from_rotation_translation_scale(rotation: Quaternion, translation: Vector3, scale: Vector3) -> Matrix4 {
         let x2 = rotation.x + rotation.x;
         let y2 = rotation.y + rotation.y;
         let z2 = rotation.z + rotation.z;
@@ -69,16 +177,50 @@ impl Matrix4 {
         }
     }
 
-    pub fn transpose(&self) -> Self {
+    /// Splits an affine transform back into the parts given to [`Matrix4::from_rotation_translation_scale`].
+    /// Shear is discarded and a mirrored basis is reported as a negative x scale.
+    pub fn to_rotation_translation_scale(&self) -> (Quaternion, Vector3, Vector3) {
+        let determinant = self.m00 * (self.m11 * self.m22 - self.m21 * self.m12)
+            - self.m10 * (self.m01 * self.m22 - self.m21 * self.m02)
+            + self.m20 * (self.m01 * self.m12 - self.m11 * self.m02);
+        let sx = sqrtf(self.m00 * self.m00 + self.m01 * self.m01 + self.m02 * self.m02);
+        let sy = sqrtf(self.m10 * self.m10 + self.m11 * self.m11 + self.m12 * self.m12);
+        let sz = sqrtf(self.m20 * self.m20 + self.m21 * self.m21 + self.m22 * self.m22);
+        let sx = if determinant < 0.0 { -sx } else { sx };
+
+        // rRC is row R, column C of the pure rotation
+        let (r00, r10, r20) = (self.m00 / sx, self.m01 / sx, self.m02 / sx);
+        let (r01, r11, r21) = (self.m10 / sy, self.m11 / sy, self.m12 / sy);
+        let (r02, r12, r22) = (self.m20 / sz, self.m21 / sz, self.m22 / sz);
+
+        let trace = r00 + r11 + r22;
+        let rotation = if trace > 0.0 {
+            let s = sqrtf(trace + 1.0) * 2.0;
+            Quaternion { x: (r21 - r12) / s, y: (r02 - r20) / s, z: (r10 - r01) / s, w: 0.25 * s }
+        } else if r00 > r11 && r00 > r22 {
+            let s = sqrtf(1.0 + r00 - r11 - r22) * 2.0;
+            Quaternion { x: 0.25 * s, y: (r01 + r10) / s, z: (r02 + r20) / s, w: (r21 - r12) / s }
+        } else if r11 > r22 {
+            let s = sqrtf(1.0 + r11 - r00 - r22) * 2.0;
+            Quaternion { x: (r01 + r10) / s, y: 0.25 * s, z: (r12 + r21) / s, w: (r02 - r20) / s }
+        } else {
+            let s = sqrtf(1.0 + r22 - r00 - r11) * 2.0;
+            Quaternion { x: (r02 + r20) / s, y: (r12 + r21) / s, z: 0.25 * s, w: (r10 - r01) / s }
+        };
+
+        (rotation, Vector3::new(self.m30, self.m31, self.m32), Vector3::new(sx, sy, sz))
+    }
+
+    pub const fn transpose(&self) -> Self {
         Matrix4 {
             m00: self.m00, m10: self.m01, m20: self.m02, m30: self.m03,
             m01: self.m10, m11: self.m11, m21: self.m12, m31: self.m13,
-            m02: self.m20, m12: self.m21, m22: self.m22, m32: self.m21,
+            m02: self.m20, m12: self.m21, m22: self.m22, m32: self.m23,
             m03: self.m30, m13: self.m31, m23: self.m32, m33: self.m33,
         }
     }
 
-    pub fn invert(&self) -> Self {
+    pub const fn invert(&self) -> Self {
         let b00 = self.m00 * self.m11 - self.m01 * self.m10;
         let b01 = self.m00 * self.m12 - self.m02 * self.m10;
         let b02 = self.m00 * self.m13 - self.m03 * self.m10;
@@ -102,7 +244,7 @@ impl Matrix4 {
         }
     }
 
-    pub fn translate(mut self, translation: Vector3) -> Self {
+    pub const fn translate(mut self, translation: Vector3) -> Self {
         self.m30 = (self.m00 * translation.x) + (self.m10 * translation.y) + (self.m20 * translation.z) + self.m30;
         self.m31 = (self.m01 * translation.x) + (self.m11 * translation.y) + (self.m21 * translation.z) + self.m31;
         self.m32 = (self.m02 * translation.x) + (self.m12 * translation.y) + (self.m22 * translation.z) + self.m32;
@@ -110,7 +252,7 @@ impl Matrix4 {
         self
     }
 
-    pub fn scale(mut self, scale: Vector3) -> Self {
+    pub const fn scale(mut self, scale: Vector3) -> Self {
         self.m00 = self.m00 * scale.x;
         self.m01 = self.m01 * scale.x;
         self.m02 = self.m02 * scale.x;
@@ -127,11 +269,49 @@ impl Matrix4 {
         self.m23 = self.m23 * scale.z;
         self
     }
-}
 
-impl Mul<Matrix4> for Matrix4 {
-    type Output = Matrix4;
-    fn mul(self, rhs: Matrix4) -> Self::Output {
+    /// Transforms a point by the affine part of the matrix, so translation applies and the
+    /// bottom row is ignored.
+    pub const fn transform_point(&self, point: Vector3) -> Vector3 {
+        Vector3::new(
+            (self.m00 * point.x) + (self.m10 * point.y) + (self.m20 * point.z) + self.m30,
+            (self.m01 * point.x) + (self.m11 * point.y) + (self.m21 * point.z) + self.m31,
+            (self.m02 * point.x) + (self.m12 * point.y) + (self.m22 * point.z) + self.m32,
+        )
+    }
+
+    /// Transforms a direction, translation is ignored.
+    pub const fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        Vector3::new(
+            (self.m00 * vector.x) + (self.m10 * vector.y) + (self.m20 * vector.z),
+            (self.m01 * vector.x) + (self.m11 * vector.y) + (self.m21 * vector.z),
+            (self.m02 * vector.x) + (self.m12 * vector.y) + (self.m22 * vector.z),
+        )
+    }
+
+    /// [`Matrix4::transform_point`] over a slice, panics if `points` and `out` differ in length.
+    pub fn transform_points(&self, points: &[Vector3], out: &mut [Vector3]) {
+        assert_eq!(points.len(), out.len(), "transform_points needs an output slot per point");
+        batch::transform(self, points, out, 1.0);
+    }
+
+    pub fn transform_points_in_place(&self, points: &mut [Vector3]) {
+        batch::transform_in_place(self, points, 1.0);
+    }
+
+    /// [`Matrix4::transform_vector`] over a slice, panics if `vectors` and `out` differ in length.
+    pub fn transform_vectors(&self, vectors: &[Vector3], out: &mut [Vector3]) {
+        assert_eq!(vectors.len(), out.len(), "transform_vectors needs an output slot per vector");
+        batch::transform(self, vectors, out, 0.0);
+    }
+
+    pub fn transform_vectors_in_place(&self, vectors: &mut [Vector3]) {
+        batch::transform_in_place(self, vectors, 0.0);
+    }
+
+    /// Same as the `*` operator, usable in constant expressions.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn mul(self, rhs: Matrix4) -> Matrix4 {
         Matrix4 {
             m00: (rhs.m00 * self.m00) + (rhs.m01 * self.m10) + (rhs.m02 * self.m20) + (rhs.m03 * self.m30),
             m01: (rhs.m00 * self.m01) + (rhs.m01 * self.m11) + (rhs.m02 * self.m21) + (rhs.m03 * self.m31),
@@ -154,4 +334,99 @@ impl Mul<Matrix4> for Matrix4 {
             m33: (rhs.m30 * self.m03) + (rhs.m31 * self.m13) + (rhs.m32 * self.m23) + (rhs.m33 * self.m33)
         }
     }
-}
\ No newline at end of file
+}
+
+/// Column `index` as a [`Vector4`], panics if `index > 3`.
+impl Index<usize> for Matrix4 {
+    type Output = Vector4;
+    fn index(&self, index: usize) -> &Vector4 {
+        assert!(index < 4, "Matrix4 column index out of range");
+        // SAFETY: both types are repr(C) with an alignment of 16 and Matrix4 is four columns of
+        // four f32s, so each column is laid out exactly like a Vector4.
+        unsafe { &*(self as *const Matrix4).cast::<Vector4>().add(index) }
+    }
+}
+
+impl IndexMut<usize> for Matrix4 {
+    fn index_mut(&mut self, index: usize) -> &mut Vector4 {
+        assert!(index < 4, "Matrix4 column index out of range");
+        // SAFETY: see `Index`.
+        unsafe { &mut *(self as *mut Matrix4).cast::<Vector4>().add(index) }
+    }
+}
+
+impl Mul<Vector4> for Matrix4 {
+    type Output = Vector4;
+    fn mul(self, rhs: Vector4) -> Self::Output {
+        Vector4 {
+            x: (self.m00 * rhs.x) + (self.m10 * rhs.y) + (self.m20 * rhs.z) + (self.m30 * rhs.w),
+            y: (self.m01 * rhs.x) + (self.m11 * rhs.y) + (self.m21 * rhs.z) + (self.m31 * rhs.w),
+            z: (self.m02 * rhs.x) + (self.m12 * rhs.y) + (self.m22 * rhs.z) + (self.m32 * rhs.w),
+            w: (self.m03 * rhs.x) + (self.m13 * rhs.y) + (self.m23 * rhs.z) + (self.m33 * rhs.w),
+        }
+    }
+}
+
+/// Columns in the same order as [`Matrix4::from_cols_array_2d`].
+impl From<[[f32; 4]; 4]> for Matrix4 {
+    fn from(value: [[f32; 4]; 4]) -> Self {
+        Matrix4::from_cols_array_2d(value)
+    }
+}
+
+impl From<Matrix4> for [[f32; 4]; 4] {
+    fn from(value: Matrix4) -> Self {
+        value.to_cols_array_2d()
+    }
+}
+
+impl From<[f32; 16]> for Matrix4 {
+    fn from(value: [f32; 16]) -> Self {
+        Matrix4::from_cols_array(value)
+    }
+}
+
+impl From<Matrix4> for [f32; 16] {
+    fn from(value: Matrix4) -> Self {
+        value.to_cols_array()
+    }
+}
+
+impl Mul<Matrix4> for Matrix4 {
+    type Output = Matrix4;
+    fn mul(self, rhs: Matrix4) -> Self::Output {
+        Matrix4::mul(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix4, Vector3, Vector4};
+
+    #[test]
+    fn columns_and_rows() {
+        let m = Matrix4::IDENTITY.translate(Vector3::new(1.0, 2.0, 3.0)).scale(Vector3::new(4.0, 5.0, 6.0));
+        assert_eq!(m[3], Vector4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(m.col(1), m[1]);
+        assert_eq!(m.row(0), Vector4::new(4.0, 0.0, 0.0, 1.0));
+        assert_eq!(Matrix4::from_cols(m[0], m[1], m[2], m[3]), m);
+        assert_eq!(Matrix4::from_rows(m.row(0), m.row(1), m.row(2), m.row(3)), m);
+        assert_eq!(Matrix4::from(m.to_cols_array_2d()), m);
+        assert_eq!(m.to_cols_array()[12..15], [1.0, 2.0, 3.0]);
+
+        let mut longer = [9.0; 17];
+        longer[..16].copy_from_slice(&m.to_cols_array());
+        assert_eq!(Matrix4::from_cols_slice(&longer), m);
+
+        let mut n = m;
+        n.set_col(3, Vector4::new(0.0, 0.0, 0.0, 1.0));
+        n[0].x = 1.0;
+        assert_eq!(n, Matrix4::IDENTITY.scale(Vector3::new(1.0, 5.0, 6.0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn column_out_of_range() {
+        let _ = Matrix4::IDENTITY[4];
+    }
+}
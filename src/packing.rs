@@ -0,0 +1,359 @@
+//! Compact encodings for vertex attributes and uniforms.
+//!
+//! Layouts follow the GLSL `packUnorm4x8` family: the first component is stored in the least
+//! significant bits, so a packed `u32` can be uploaded as is and unpacked in a shader with the
+//! matching built-in or vertex format. Every encoding has a decode counterpart and documents the
+//! worst case absolute error of a round trip for inputs in its valid range.
+
+use crate::{Quaternion, Vector2, Vector3, Vector4};
+use libm::{fabsf, fmaxf, fminf, roundf, sqrtf};
+
+/// Converts to the nearest IEEE 754 binary16 value, rounding ties to even.
+/// Values above 65504 become infinity and NaN stays NaN.
+/// The relative error is at most 2^-11 for normal results (magnitudes `6.1e-5..=65504`),
+/// smaller values lose precision gradually down to an absolute error of 2^-25.
+pub fn f32_to_f16(value: f32) -> u16 {
+	let bits = value.to_bits();
+	let sign = ((bits >> 16) & 0x8000) as u16;
+	let exponent = ((bits >> 23) & 0xff) as i32;
+	let mantissa = bits & 0x007f_ffff;
+
+	if exponent == 0xff {
+		let nan = if mantissa != 0 { 0x0200 | (mantissa >> 13) as u16 } else { 0 };
+		return sign | 0x7c00 | nan;
+	}
+
+	let half_exponent = exponent - 127 + 15;
+	if half_exponent >= 0x1f {
+		return sign | 0x7c00;
+	}
+	if half_exponent <= 0 {
+		if half_exponent < -10 {
+			return sign;
+		}
+		let mantissa = mantissa | 0x0080_0000;
+		let shift = (14 - half_exponent) as u32;
+		return sign | round_shift(mantissa, shift) as u16;
+	}
+
+	// A carry out of the mantissa correctly rounds up into the exponent, or to infinity.
+	let half = ((half_exponent as u32) << 10) | (mantissa >> 13);
+	let remainder = mantissa & 0x1fff;
+	let round_up = (remainder > 0x1000) || ((remainder == 0x1000) && ((half & 1) != 0));
+	sign | (half + round_up as u32) as u16
+}
+
+/// Converts an IEEE 754 binary16 value to `f32`, this is always exact.
+pub fn f16_to_f32(value: u16) -> f32 {
+	let sign = ((value & 0x8000) as u32) << 16;
+	let exponent = ((value >> 10) & 0x1f) as u32;
+	let mantissa = (value & 0x03ff) as u32;
+
+	let bits = match exponent {
+		0 => {
+			let magnitude = mantissa as f32 * (1.0 / 16_777_216.0);
+			return f32::from_bits(sign | magnitude.to_bits());
+		}
+		0x1f => sign | 0x7f80_0000 | (mantissa << 13),
+		_ => sign | ((exponent + 112) << 23) | (mantissa << 13),
+	};
+	f32::from_bits(bits)
+}
+
+fn round_shift(value: u32, shift: u32) -> u32 {
+	let truncated = value >> shift;
+	let remainder = value & ((1 << shift) - 1);
+	let halfway = 1 << (shift - 1);
+	let round_up = (remainder > halfway) || ((remainder == halfway) && ((truncated & 1) != 0));
+	truncated + round_up as u32
+}
+
+/// Packs two half floats, see [`f32_to_f16`] for the error bound.
+pub fn pack_half2x16(value: Vector2) -> u32 {
+	(f32_to_f16(value.x) as u32) | ((f32_to_f16(value.y) as u32) << 16)
+}
+
+pub fn unpack_half2x16(packed: u32) -> Vector2 {
+	Vector2 {
+		x: f16_to_f32(packed as u16),
+		y: f16_to_f32((packed >> 16) as u16),
+	}
+}
+
+/// Packs a [`Vector3`] as three half floats, padded to a four component vertex format.
+pub fn pack_half3x16(value: Vector3) -> [u16; 4] {
+	[f32_to_f16(value.x), f32_to_f16(value.y), f32_to_f16(value.z), 0]
+}
+
+pub fn unpack_half3x16(packed: [u16; 4]) -> Vector3 {
+	Vector3::new(f16_to_f32(packed[0]), f16_to_f32(packed[1]), f16_to_f32(packed[2]))
+}
+
+pub fn pack_half4x16(value: Vector4) -> [u16; 4] {
+	[f32_to_f16(value.x), f32_to_f16(value.y), f32_to_f16(value.z), f32_to_f16(value.w)]
+}
+
+pub fn unpack_half4x16(packed: [u16; 4]) -> Vector4 {
+	Vector4 {
+		x: f16_to_f32(packed[0]),
+		y: f16_to_f32(packed[1]),
+		z: f16_to_f32(packed[2]),
+		w: f16_to_f32(packed[3]),
+	}
+}
+
+fn to_unorm(value: f32, max: f32) -> u32 {
+	roundf(fminf(fmaxf(value, 0.0), 1.0) * max) as u32
+}
+
+fn to_snorm(value: f32, max: f32) -> i32 {
+	roundf(fminf(fmaxf(value, -1.0), 1.0) * max) as i32
+}
+
+fn from_snorm(value: i32, max: f32) -> f32 {
+	fmaxf(value as f32 / max, -1.0)
+}
+
+/// Packs four components clamped to `0.0..=1.0` with an error of at most 1/510.
+pub fn pack_unorm4x8(value: Vector4) -> u32 {
+	to_unorm(value.x, 255.0) | (to_unorm(value.y, 255.0) << 8) | (to_unorm(value.z, 255.0) << 16) | (to_unorm(value.w, 255.0) << 24)
+}
+
+pub fn unpack_unorm4x8(packed: u32) -> Vector4 {
+	Vector4 {
+		x: (packed & 0xff) as f32 / 255.0,
+		y: ((packed >> 8) & 0xff) as f32 / 255.0,
+		z: ((packed >> 16) & 0xff) as f32 / 255.0,
+		w: (packed >> 24) as f32 / 255.0,
+	}
+}
+
+/// Packs four components clamped to `-1.0..=1.0` with an error of at most 1/254.
+pub fn pack_snorm4x8(value: Vector4) -> u32 {
+	let x = to_snorm(value.x, 127.0) as u8 as u32;
+	let y = to_snorm(value.y, 127.0) as u8 as u32;
+	let z = to_snorm(value.z, 127.0) as u8 as u32;
+	let w = to_snorm(value.w, 127.0) as u8 as u32;
+	x | (y << 8) | (z << 16) | (w << 24)
+}
+
+pub fn unpack_snorm4x8(packed: u32) -> Vector4 {
+	Vector4 {
+		x: from_snorm(packed as u8 as i8 as i32, 127.0),
+		y: from_snorm((packed >> 8) as u8 as i8 as i32, 127.0),
+		z: from_snorm((packed >> 16) as u8 as i8 as i32, 127.0),
+		w: from_snorm((packed >> 24) as u8 as i8 as i32, 127.0),
+	}
+}
+
+/// Packs two components clamped to `0.0..=1.0` with an error of at most 1/131070.
+pub fn pack_unorm2x16(value: Vector2) -> u32 {
+	to_unorm(value.x, 65535.0) | (to_unorm(value.y, 65535.0) << 16)
+}
+
+pub fn unpack_unorm2x16(packed: u32) -> Vector2 {
+	Vector2 {
+		x: (packed & 0xffff) as f32 / 65535.0,
+		y: (packed >> 16) as f32 / 65535.0,
+	}
+}
+
+/// Packs two components clamped to `-1.0..=1.0` with an error of at most 1/65534.
+pub fn pack_snorm2x16(value: Vector2) -> u32 {
+	let x = to_snorm(value.x, 32767.0) as u16 as u32;
+	let y = to_snorm(value.y, 32767.0) as u16 as u32;
+	x | (y << 16)
+}
+
+pub fn unpack_snorm2x16(packed: u32) -> Vector2 {
+	Vector2 {
+		x: from_snorm(packed as u16 as i16 as i32, 32767.0),
+		y: from_snorm((packed >> 16) as u16 as i16 as i32, 32767.0),
+	}
+}
+
+/// Packs rgb with 10 bits and alpha with 2 bits, each clamped to `0.0..=1.0`, in the
+/// `A2B10G10R10` layout used by `GL_UNSIGNED_INT_2_10_10_10_REV` and `R10G10B10A2_UNORM`.
+/// The error is at most 1/2046 for rgb and 1/6 for alpha.
+pub fn pack_rgb10a2(value: Vector4) -> u32 {
+	to_unorm(value.x, 1023.0) | (to_unorm(value.y, 1023.0) << 10) | (to_unorm(value.z, 1023.0) << 20) | (to_unorm(value.w, 3.0) << 30)
+}
+
+pub fn unpack_rgb10a2(packed: u32) -> Vector4 {
+	Vector4 {
+		x: (packed & 0x3ff) as f32 / 1023.0,
+		y: ((packed >> 10) & 0x3ff) as f32 / 1023.0,
+		z: ((packed >> 20) & 0x3ff) as f32 / 1023.0,
+		w: (packed >> 30) as f32 / 3.0,
+	}
+}
+
+fn sign_not_zero(value: f32) -> f32 {
+	if value >= 0.0 { 1.0 } else { -1.0 }
+}
+
+/// Maps a unit vector onto the `[-1, 1]` square by projecting onto an octahedron and folding the
+/// lower hemisphere over the upper one. The result is exact, quantize it with
+/// [`pack_snorm2x16`] or use [`pack_octahedral`].
+pub fn encode_octahedral(normal: Vector3) -> Vector2 {
+	let l1 = fabsf(normal.x) + fabsf(normal.y) + fabsf(normal.z);
+	let x = normal.x / l1;
+	let y = normal.y / l1;
+	if normal.z < 0.0 {
+		Vector2 {
+			x: (1.0 - fabsf(y)) * sign_not_zero(x),
+			y: (1.0 - fabsf(x)) * sign_not_zero(y),
+		}
+	} else {
+		Vector2 { x, y }
+	}
+}
+
+/// Inverse of [`encode_octahedral`], the result is normalized.
+pub fn decode_octahedral(encoded: Vector2) -> Vector3 {
+	let z = 1.0 - fabsf(encoded.x) - fabsf(encoded.y);
+	let (x, y) = if z < 0.0 {
+		((1.0 - fabsf(encoded.y)) * sign_not_zero(encoded.x), (1.0 - fabsf(encoded.x)) * sign_not_zero(encoded.y))
+	} else {
+		(encoded.x, encoded.y)
+	};
+	let mut normal = Vector3::new(x, y, z);
+	normal.normalize();
+	normal
+}
+
+/// Packs a unit vector into 32 bits as two snorm16 octahedral coordinates.
+/// The angle between the input and the decoded normal is at most 0.005 degrees.
+pub fn pack_octahedral(normal: Vector3) -> u32 {
+	pack_snorm2x16(encode_octahedral(normal))
+}
+
+pub fn unpack_octahedral(packed: u32) -> Vector3 {
+	decode_octahedral(unpack_snorm2x16(packed))
+}
+
+/// Packs a unit quaternion into 32 bits with the "smallest three" scheme: the index of the
+/// largest component is stored in the top 2 bits and the other three, which must lie within
+/// `±1/√2`, are stored with 10 bits each. The largest component is rebuilt from the unit length.
+/// `q` and `-q` are the same rotation so the sign is not preserved.
+/// Each component of the decoded quaternion is within 0.002 of the (sign adjusted) input.
+pub fn pack_quaternion(rotation: Quaternion) -> u32 {
+	let components = [rotation.x, rotation.y, rotation.z, rotation.w];
+	let mut largest = 0;
+	for i in 1..4 {
+		if fabsf(components[i]) > fabsf(components[largest]) {
+			largest = i;
+		}
+	}
+	let sign = sign_not_zero(components[largest]);
+
+	let mut packed = (largest as u32) << 30;
+	let mut shift: u32 = 20;
+	for (i, component) in components.into_iter().enumerate() {
+		if i != largest {
+			let snorm = to_snorm(component * sign * core::f32::consts::SQRT_2, 511.0);
+			packed |= ((snorm + 511) as u32) << shift;
+			shift = shift.wrapping_sub(10);
+		}
+	}
+	packed
+}
+
+pub fn unpack_quaternion(packed: u32) -> Quaternion {
+	let largest = (packed >> 30) as usize;
+	let mut components = [0.0; 4];
+	let mut shift: u32 = 20;
+	let mut sum = 0.0;
+	for (i, component) in components.iter_mut().enumerate() {
+		if i != largest {
+			let snorm = ((packed >> shift) & 0x3ff) as i32 - 511;
+			*component = from_snorm(snorm, 511.0) * core::f32::consts::FRAC_1_SQRT_2;
+			sum += *component * *component;
+			shift = shift.wrapping_sub(10);
+		}
+	}
+	components[largest] = sqrtf(fmaxf(1.0 - sum, 0.0));
+	Quaternion { x: components[0], y: components[1], z: components[2], w: components[3] }
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::packing::*;
+	use crate::{Quaternion, Vector2, Vector3, Vector4};
+
+	#[test]
+	fn half() {
+		assert_eq!(f32_to_f16(1.0), 0x3c00);
+		assert_eq!(f32_to_f16(-2.0), 0xc000);
+		assert_eq!(f32_to_f16(65504.0), 0x7bff);
+		assert_eq!(f32_to_f16(65520.0), 0x7c00);
+		assert_eq!(f32_to_f16(f32::INFINITY), 0x7c00);
+		assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+		assert_eq!(f32_to_f16(5.960_464_5e-8), 0x0001);
+		assert_eq!(f32_to_f16(1.0 + (1.0 / 4096.0)), 0x3c00);
+		for bits in 0..0x7c00u16 {
+			assert_eq!(f32_to_f16(f16_to_f32(bits)), bits);
+			assert_eq!(f32_to_f16(-f16_to_f32(bits)), bits | 0x8000);
+		}
+		let v = Vector2 { x: 0.333, y: -1024.5 };
+		let decoded = unpack_half2x16(pack_half2x16(v));
+		assert!(((decoded.x - v.x) / v.x).abs() <= 1.0 / 2048.0);
+		assert_eq!(decoded.y, -1024.0);
+	}
+
+	#[test]
+	fn norm() {
+		for i in 0..=1000 {
+			let t = i as f32 / 1000.0;
+			let v = Vector4 { x: t, y: 1.0 - t, z: -t, w: (t * 2.0) - 1.0 };
+			let unorm = unpack_unorm4x8(pack_unorm4x8(v));
+			assert!((unorm.x - v.x).abs() <= 1.0 / 510.0 + f32::EPSILON);
+			assert_eq!(unorm.z, 0.0);
+			let snorm = unpack_snorm4x8(pack_snorm4x8(v));
+			assert!((snorm.z - v.z).abs() <= 1.0 / 254.0 + f32::EPSILON);
+			assert!((snorm.w - v.w).abs() <= 1.0 / 254.0 + f32::EPSILON);
+			let v2 = Vector2 { x: v.x, y: v.w };
+			let unorm = unpack_unorm2x16(pack_unorm2x16(v2));
+			assert!((unorm.x - v2.x).abs() <= 1.0 / 131070.0 + f32::EPSILON);
+			let snorm = unpack_snorm2x16(pack_snorm2x16(v2));
+			assert!((snorm.y - v2.y).abs() <= 1.0 / 65534.0 + f32::EPSILON);
+			let rgb10a2 = unpack_rgb10a2(pack_rgb10a2(v));
+			assert!((rgb10a2.y - v.y).abs() <= 1.0 / 2046.0 + f32::EPSILON);
+		}
+		assert_eq!(pack_unorm4x8(Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.0 }), 0x0000_00ff);
+		assert_eq!(pack_snorm4x8(Vector4 { x: -1.0, y: 0.0, z: 0.0, w: 1.0 }), 0x7f00_0081);
+		assert_eq!(pack_rgb10a2(Vector4::ONE), 0xffff_ffff);
+	}
+
+	#[test]
+	fn octahedral() {
+		let mut worst: f32 = 0.0;
+		for i in 0..64 {
+			for j in 0..128 {
+				let theta = (i as f32 + 0.5) / 64.0 * core::f32::consts::PI;
+				let phi = j as f32 / 128.0 * core::f32::consts::TAU;
+				let n = Vector3::new(libm::sinf(theta) * libm::cosf(phi), libm::sinf(theta) * libm::sinf(phi), libm::cosf(theta));
+				let d = unpack_octahedral(pack_octahedral(n));
+				worst = worst.max((n - d).magnitude());
+			}
+		}
+		assert!(worst.to_degrees() < 0.005);
+		assert_eq!(unpack_octahedral(pack_octahedral(Vector3::BACK)), Vector3::BACK);
+	}
+
+	#[test]
+	fn quaternion() {
+		for i in 0..32 {
+			for j in 0..32 {
+				let q = Quaternion::from_euler_degrees(Vector3::new(i as f32 * 11.25, j as f32 * 11.25 - 180.0, 30.0));
+				let d = unpack_quaternion(pack_quaternion(q));
+				let sign = if (q.x * d.x) + (q.y * d.y) + (q.z * d.z) + (q.w * d.w) < 0.0 { -1.0 } else { 1.0 };
+				assert!((q.x * sign - d.x).abs() < 0.002);
+				assert!((q.y * sign - d.y).abs() < 0.002);
+				assert!((q.z * sign - d.z).abs() < 0.002);
+				assert!((q.w * sign - d.w).abs() < 0.002);
+			}
+		}
+		assert_eq!(unpack_quaternion(pack_quaternion(Quaternion::IDENTITY)), Quaternion::IDENTITY);
+	}
+}
@@ -0,0 +1,44 @@
+use crate::Vector3;
+
+/// The points where `normal.dot(point) == distance`, `normal` is normalized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+	pub normal: Vector3,
+	pub distance: f32,
+}
+
+impl Plane {
+	pub const fn new(normal: Vector3, distance: f32) -> Self {
+		Plane { normal, distance }
+	}
+
+	/// The plane through `a`, `b` and `c`, facing the side they appear counter-clockwise from.
+	/// `None` when they are collinear.
+	pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Option<Self> {
+		let normal = (b - a).cross(c - a);
+		let magnitude = normal.magnitude();
+		if magnitude <= 0.0 || magnitude.is_nan() {
+			return None;
+		}
+		let normal = normal / magnitude;
+		Some(Plane { normal, distance: normal.dot(a) })
+	}
+
+	/// Positive in front of the plane, negative behind it.
+	pub fn signed_distance(&self, point: Vector3) -> f32 {
+		self.normal.dot(point) - self.distance
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Plane, Vector3};
+
+	#[test]
+	fn from_points() {
+		let plane = Plane::from_points(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 2.0, 1.0), Vector3::new(1.0, 2.0, 0.0)).unwrap();
+		assert_eq!(plane, Plane::new(Vector3::UP, 2.0));
+		assert_eq!(plane.signed_distance(Vector3::new(5.0, -1.0, 3.0)), -3.0);
+		assert_eq!(Plane::from_points(Vector3::ZERO, Vector3::ONE, Vector3::splat(2.0)), None);
+	}
+}
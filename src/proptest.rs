@@ -0,0 +1,138 @@
+//! [`proptest`](::proptest) strategies, enabled by the `proptest` feature.
+//!
+//! These mirror the wrappers in the `arbitrary` feature: finite vectors, unit vectors,
+//! normalized quaternions and affine or invertible matrices.
+
+use crate::generate;
+use crate::{IVector2, Matrix4, Quaternion, Vector2, Vector3, Vector4};
+use ::proptest::prelude::*;
+use core::ops::Range;
+
+/// Bound on the components of finite vectors and the translation of affine matrices.
+pub const FINITE_BOUND: f32 = 1.0e4;
+
+const FINITE: Range<f32> = -FINITE_BOUND..FINITE_BOUND;
+
+/// Vectors with every component in `[-FINITE_BOUND, FINITE_BOUND)`.
+pub fn finite_vector2() -> impl Strategy<Value = Vector2> {
+	(FINITE, FINITE).prop_map(|(x, y)| Vector2 { x, y })
+}
+
+/// Vectors with every component in `[-FINITE_BOUND, FINITE_BOUND)`.
+pub fn finite_vector3() -> impl Strategy<Value = Vector3> {
+	(FINITE, FINITE, FINITE).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+/// Vectors with every component in `[-FINITE_BOUND, FINITE_BOUND)`.
+pub fn finite_vector4() -> impl Strategy<Value = Vector4> {
+	(FINITE, FINITE, FINITE, FINITE).prop_map(|(x, y, z, w)| Vector4 { x, y, z, w })
+}
+
+/// Integer vectors over the whole `i32` range.
+pub fn ivector2() -> impl Strategy<Value = IVector2> {
+	(any::<i32>(), any::<i32>()).prop_map(|(x, y)| IVector2 { x, y })
+}
+
+/// Unit length vectors, uniformly distributed over directions.
+pub fn unit_vector3() -> impl Strategy<Value = Vector3> {
+	(-1.0f32..=1.0, 0.0f32..1.0).prop_map(|(z, turn)| generate::unit_vector3(z, turn))
+}
+
+/// Normalized quaternions, uniformly distributed over rotations.
+pub fn unit_quaternion() -> impl Strategy<Value = Quaternion> {
+	(0.0f32..1.0, 0.0f32..1.0, 0.0f32..1.0).prop_map(|(u1, u2, u3)| generate::unit_quaternion(u1, u2, u3))
+}
+
+/// Scale between 0.1 and 10 on each axis, any of which may be mirrored.
+fn scale() -> impl Strategy<Value = Vector3> {
+	let axis = || (0.0f32..1.0, any::<bool>()).prop_map(|(t, negative)| generate::scale_factor(t, negative));
+	(axis(), axis(), axis()).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+/// Matrices built by [`Matrix4::from_rotation_translation_scale`] from a normalized rotation,
+/// a finite translation and a scale between 0.1 and 10 on each axis, possibly mirrored.
+pub fn affine_matrix4() -> impl Strategy<Value = Matrix4> {
+	(unit_quaternion(), finite_vector3(), scale())
+		.prop_map(|(rotation, translation, scale)| Matrix4::from_rotation_translation_scale(rotation, translation, scale))
+}
+
+/// Well conditioned, and so safely invertible, general matrices.
+pub fn invertible_matrix4() -> impl Strategy<Value = Matrix4> {
+	prop::array::uniform16(-1.0f32..=1.0).prop_map(generate::invertible_matrix4)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::packing;
+
+	fn approx(a: f32, b: f32, tolerance: f32) -> bool {
+		(a - b).abs() <= tolerance * (1.0 + a.abs().max(b.abs()))
+	}
+
+	fn matrix_approx(a: Matrix4, b: Matrix4, tolerance: f32) -> bool {
+		a.to_cols_array().iter().zip(b.to_cols_array()).all(|(a, b)| approx(*a, b, tolerance))
+	}
+
+	fn same_rotation(a: Quaternion, b: Quaternion) -> bool {
+		let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+		approx(dot.abs(), 1.0, 1e-4)
+	}
+
+	proptest! {
+		#[test]
+		fn transpose_is_an_involution(m in invertible_matrix4()) {
+			prop_assert_eq!(m.transpose().transpose(), m);
+		}
+
+		#[test]
+		fn invert(m in invertible_matrix4()) {
+			prop_assert!(matrix_approx(m * m.invert(), Matrix4::IDENTITY, 1e-5));
+			prop_assert!(matrix_approx(m.invert() * m, Matrix4::IDENTITY, 1e-5));
+		}
+
+		#[test]
+		fn invert_affine(m in affine_matrix4()) {
+			prop_assert!(matrix_approx(m.invert().invert(), m, 1e-3));
+		}
+
+		#[test]
+		fn decompose(rotation in unit_quaternion(), translation in finite_vector3(), scale in scale()) {
+			let m = Matrix4::from_rotation_translation_scale(rotation, translation, scale);
+			let (r, t, s) = m.to_rotation_translation_scale();
+			prop_assert!(matrix_approx(Matrix4::from_rotation_translation_scale(r, t, s), m, 1e-4));
+		}
+
+		#[test]
+		fn euler(rotation in unit_quaternion()) {
+			prop_assert!(same_rotation(Quaternion::from_euler_degrees(rotation.to_euler()), rotation));
+		}
+
+		#[test]
+		fn rotations_compose(a in unit_quaternion(), b in unit_quaternion()) {
+			prop_assert!(approx((a * b).magnitude(), 1.0, 1e-5));
+		}
+
+		#[test]
+		fn unit_vectors(v in unit_vector3()) {
+			prop_assert!(approx(v.magnitude(), 1.0, 1e-5));
+			let decoded = packing::unpack_octahedral(packing::pack_octahedral(v));
+			prop_assert!((decoded - v).magnitude() < 1e-4);
+		}
+
+		#[test]
+		fn vector_arithmetic(a in finite_vector3(), b in finite_vector3()) {
+			let v = (a + b) - b;
+			prop_assert!(approx(v.x, a.x, 1e-3) && approx(v.y, a.y, 1e-3) && approx(v.z, a.z, 1e-3));
+			prop_assert_eq!(a.min(b).max(a), a);
+		}
+
+		#[test]
+		fn chunks(cell in ivector2(), size in (1..64, 1..64)) {
+			let size = IVector2 { x: size.0, y: size.1 };
+			let (chunk, local) = cell.split_chunk(size);
+			prop_assert!(local.x >= 0 && local.x < size.x && local.y >= 0 && local.y < size.y);
+			prop_assert_eq!(IVector2::from_chunk(chunk, local, size), cell);
+		}
+	}
+}
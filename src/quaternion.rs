@@ -1,10 +1,12 @@
-use crate::Vector3;
+use crate::batch;
+use crate::scalar::signumf;
+use crate::{Degrees, Radians, Vector3};
 use core::ops::{Mul, MulAssign};
-use libm::{sinf, cosf};
+use libm::{asinf, atan2f, cosf, fabsf, sinf, sqrtf};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Quaternion {
     pub x: f32,
     pub y: f32,
@@ -15,11 +17,40 @@ pub struct Quaternion {
 impl Quaternion {
     pub const IDENTITY: Quaternion = Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
 
-    pub fn from_euler(euler: Vector3) -> Quaternion {
-        const HALF_TO_RAD: f32 = core::f32::consts::PI / 360.0;
-        let x = euler.x * HALF_TO_RAD;
-        let y = euler.y * HALF_TO_RAD;
-        let z = euler.z * HALF_TO_RAD;
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        Quaternion { x, y, z, w }
+    }
+
+    /// Same as the `*` operator, usable in constant expressions. Applies `rhs` first, then `self`.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion {
+            x: self.x * rhs.w + self.w * rhs.x + self.y * rhs.z - self.z * rhs.y,
+            y: self.y * rhs.w + self.w * rhs.y + self.z * rhs.x - self.x * rhs.z,
+            z: self.z * rhs.w + self.w * rhs.z + self.x * rhs.y - self.y * rhs.x,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z
+        }
+    }
+
+    /// Rotation of `angle` around `axis`, which must be normalized.
+    pub fn from_axis_angle(axis: Vector3, angle: impl Into<Radians>) -> Quaternion {
+        let Radians(angle) = angle.into();
+        let s = sinf(angle * 0.5);
+        let c = cosf(angle * 0.5);
+        Quaternion { x: axis.x * s, y: axis.y * s, z: axis.z * s, w: c }
+    }
+
+    /// Rotation of `angle` degrees around `axis`, which must be normalized.
+    pub fn from_axis_angle_degrees(axis: Vector3, angle: f32) -> Quaternion {
+        Quaternion::from_axis_angle(axis, Degrees(angle))
+    }
+
+    /// Rotation from the x, y and z Euler angles.
+    pub fn from_euler<A: Into<Radians>>(euler: [A; 3]) -> Quaternion {
+        let [Radians(x), Radians(y), Radians(z)] = euler.map(Into::into);
+        let x = x * 0.5;
+        let y = y * 0.5;
+        let z = z * 0.5;
 
         let sx = sinf(x);
         let cx = cosf(x);
@@ -35,30 +66,106 @@ impl Quaternion {
             w: cx * cy * cz + sx * sy * sz
         }
     }
+
+    /// Rotation from the x, y and z Euler angles of `euler`, in degrees.
+    pub fn from_euler_degrees(euler: Vector3) -> Quaternion {
+        Quaternion::from_euler([Degrees(euler.x), Degrees(euler.y), Degrees(euler.z)])
+    }
+
+    /// Inverse of [`Quaternion::from_euler_degrees`], returns the angles in degrees.
+    /// The y angle is in `[-90, 90]`, when it reaches either end the z angle absorbs the x rotation.
+    pub fn to_euler(&self) -> Vector3 {
+        const TO_DEG: f32 = 180.0 / core::f32::consts::PI;
+        let sin_y = (2.0 * (self.w * self.y - self.x * self.z)).clamp(-1.0, 1.0);
+        if fabsf(sin_y) > 0.99999 {
+            let z = -2.0 * atan2f(self.x, self.w);
+            return Vector3::new(0.0, asinf(sin_y) * TO_DEG, z * TO_DEG * signumf(sin_y));
+        }
+
+        let x = atan2f(2.0 * (self.w * self.x + self.y * self.z), 1.0 - 2.0 * (self.x * self.x + self.y * self.y));
+        let z = atan2f(2.0 * (self.w * self.z + self.x * self.y), 1.0 - 2.0 * (self.y * self.y + self.z * self.z));
+        Vector3::new(x * TO_DEG, asinf(sin_y) * TO_DEG, z * TO_DEG)
+    }
+
+    /// Rotates `vector`, `self` should be normalized.
+    pub const fn rotate(&self, vector: Vector3) -> Vector3 {
+        let axis = Vector3::new(self.x, self.y, self.z);
+        let t = axis.cross(vector).scale(2.0);
+        vector.add(t.scale(self.w)).add(axis.cross(t))
+    }
+
+    /// The inverse rotation, `self` should be normalized.
+    pub const fn conjugate(&self) -> Quaternion {
+        Quaternion { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+    }
+
+    /// [`Quaternion::rotate`] over a slice in place.
+    pub fn rotate_slice(&self, vectors: &mut [Vector3]) {
+        batch::rotate_in_place(self, vectors);
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        sqrtf((self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w))
+    }
+
+    pub fn normalize(&mut self) {
+        let magnitude = self.magnitude();
+        self.x /= magnitude;
+        self.y /= magnitude;
+        self.z /= magnitude;
+        self.w /= magnitude;
+    }
 }
 
 impl Mul<Quaternion> for Quaternion {
     type Output = Quaternion;
     fn mul(self, rhs: Quaternion) -> Self::Output {
-        Quaternion {
-            x: self.x * rhs.w + self.w * rhs.x + self.y * rhs.z - self.z * rhs.y,
-            y: self.y * rhs.w + self.w * rhs.y + self.z * rhs.x - self.x * rhs.z,
-            z: self.z * rhs.w + self.w * rhs.z + self.x * rhs.y - self.y * rhs.x,
-            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z
-        }
+        Quaternion::mul(self, rhs)
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        self.rotate(rhs)
     }
 }
 
 impl MulAssign<Quaternion> for Quaternion {
     fn mul_assign(&mut self, rhs: Quaternion) {
-        let ax = self.x * rhs.w + self.w * rhs.x + self.y * rhs.z - self.z * rhs.y;
-        let ay = self.y * rhs.w + self.w * rhs.y + self.z * rhs.x - self.x * rhs.z;
-        let az = self.z * rhs.w + self.w * rhs.z + self.x * rhs.y - self.y * rhs.x;
-        let aw = self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z;
+        *self = Quaternion::mul(*self, rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix4, Quaternion, Vector3};
 
-        self.x = ax;
-        self.y = ay;
-        self.z = az;
-        self.w = aw;
+    fn same_rotation(a: Quaternion, b: Quaternion) -> bool {
+        let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+        (dot.abs() - 1.0).abs() < 1e-5
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn euler() {
+        for euler in [Vector3::new(30.0, 45.0, -60.0), Vector3::new(-170.0, -20.0, 100.0), Vector3::new(10.0, 90.0, 20.0), Vector3::new(10.0, -90.0, 20.0)] {
+            let rotation = Quaternion::from_euler_degrees(euler);
+            assert!(same_rotation(Quaternion::from_euler_degrees(rotation.to_euler()), rotation));
+        }
+        let angles = Quaternion::from_euler_degrees(Vector3::new(30.0, 45.0, -60.0)).to_euler();
+        assert!((angles.x - 30.0).abs() < 1e-3 && (angles.y - 45.0).abs() < 1e-3 && (angles.z + 60.0).abs() < 1e-3);
+        assert!(same_rotation(Quaternion::from_axis_angle_degrees(Vector3::RIGHT, 90.0), Quaternion::from_euler_degrees(Vector3::new(90.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn const_builders() {
+        const HALF_TURN: Quaternion = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+        const FULL_TURN: Quaternion = HALF_TURN.mul(HALF_TURN);
+        const MODEL: Matrix4 = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+            .mul(Matrix4::from_rotation_translation_scale(HALF_TURN, Vector3::ZERO, Vector3::splat(2.0)));
+        assert!(same_rotation(FULL_TURN, Quaternion::IDENTITY));
+        assert_eq!(HALF_TURN * HALF_TURN, FULL_TURN);
+        assert_eq!(MODEL, Matrix4::IDENTITY.translate(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_rotation_translation_scale(HALF_TURN, Vector3::ZERO, Vector3::splat(2.0)));
+        assert_eq!(Matrix4::from_scale(Vector3::splat(2.0)), Matrix4::IDENTITY.scale(Vector3::splat(2.0)));
+    }
+}
@@ -0,0 +1,206 @@
+//! Random generation, enabled by the `rand` feature.
+//!
+//! [`StandardUniform`] samples vector components in `[0, 1)`, [`IVector2`] over the whole `i32`
+//! range and [`Quaternion`] as a uniformly distributed rotation. Vectors also implement
+//! [`SampleUniform`], so [`Uniform::new(min, max)`](rand::distr::Uniform::new) picks each
+//! component within its bounds, and `Uniform::new_inclusive` gives an [`IVector2`] within a
+//! grid's bounds. The remaining shapes are distributions of their own.
+
+use crate::generate;
+use crate::{Degrees, IVector2, Quaternion, Vector2, Vector3, Vector4};
+use core::f32::consts::TAU;
+use libm::{cbrtf, copysignf, cosf, sinf, sqrtf};
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformFloat, UniformInt, UniformSampler};
+use rand::distr::{Distribution, StandardUniform};
+use rand::Rng;
+
+macro_rules! impl_random_vector {
+	($ty:ident, $sampler:ident, $scalar:ty, $uniform:ty, [$($field:ident),+], $build:expr) => {
+		impl Distribution<$ty> for StandardUniform {
+			fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+				$(let $field: $scalar = rng.random();)+
+				$build
+			}
+		}
+
+		#[doc = concat!("Samples each component of a [`", stringify!($ty), "`] within its own range.")]
+		#[derive(Clone, Copy, Debug, PartialEq)]
+		pub struct $sampler {
+			$($field: $uniform,)+
+		}
+
+		impl UniformSampler for $sampler {
+			type X = $ty;
+
+			fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+			where
+				B1: SampleBorrow<$ty> + Sized,
+				B2: SampleBorrow<$ty> + Sized,
+			{
+				let (low, high) = (low.borrow(), high.borrow());
+				Ok($sampler { $($field: <$uniform>::new(low.$field, high.$field)?,)+ })
+			}
+
+			fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+			where
+				B1: SampleBorrow<$ty> + Sized,
+				B2: SampleBorrow<$ty> + Sized,
+			{
+				let (low, high) = (low.borrow(), high.borrow());
+				Ok($sampler { $($field: <$uniform>::new_inclusive(low.$field, high.$field)?,)+ })
+			}
+
+			fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+				$(let $field = self.$field.sample(rng);)+
+				$build
+			}
+		}
+
+		impl SampleUniform for $ty {
+			type Sampler = $sampler;
+		}
+	};
+}
+
+impl_random_vector!(Vector2, UniformVector2, f32, UniformFloat<f32>, [x, y], Vector2 { x, y });
+impl_random_vector!(Vector3, UniformVector3, f32, UniformFloat<f32>, [x, y, z], Vector3::new(x, y, z));
+impl_random_vector!(Vector4, UniformVector4, f32, UniformFloat<f32>, [x, y, z, w], Vector4 { x, y, z, w });
+impl_random_vector!(IVector2, UniformIVector2, i32, UniformInt<i32>, [x, y], IVector2 { x, y });
+
+/// Uniformly distributed rotations, using Shoemake's subgroup algorithm.
+impl Distribution<Quaternion> for StandardUniform {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion {
+		generate::unit_quaternion(rng.random(), rng.random(), rng.random())
+	}
+}
+
+/// Unit length [`Vector2`] pointing in a uniformly random direction.
+#[derive(Clone, Copy, Debug)]
+pub struct OnUnitCircle;
+
+impl Distribution<Vector2> for OnUnitCircle {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+		let angle = TAU * rng.random::<f32>();
+		Vector2 { x: cosf(angle), y: sinf(angle) }
+	}
+}
+
+/// [`Vector2`] uniformly distributed over the area of the unit disk.
+#[derive(Clone, Copy, Debug)]
+pub struct InUnitDisk;
+
+impl Distribution<Vector2> for InUnitDisk {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+		OnUnitCircle.sample(rng) * sqrtf(rng.random())
+	}
+}
+
+/// Unit length [`Vector3`] pointing in a uniformly random direction.
+#[derive(Clone, Copy, Debug)]
+pub struct OnUnitSphere;
+
+impl Distribution<Vector3> for OnUnitSphere {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+		generate::unit_vector3(2.0 * rng.random::<f32>() - 1.0, rng.random())
+	}
+}
+
+/// [`Vector3`] uniformly distributed over the volume of the unit sphere.
+#[derive(Clone, Copy, Debug)]
+pub struct InUnitSphere;
+
+impl Distribution<Vector3> for InUnitSphere {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+		OnUnitSphere.sample(rng) * cbrtf(rng.random())
+	}
+}
+
+/// Unit length [`Vector3`] uniformly distributed over the cap of directions within
+/// `half_angle` degrees of `direction`, as for spread in particle emitters and weapons.
+#[derive(Clone, Copy, Debug)]
+pub struct Cone {
+	direction: Vector3,
+	cos_half_angle: f32,
+}
+
+impl Cone {
+	/// `direction` does not need to be normalized, `half_angle` is clamped to `[0, 180]` degrees.
+	pub fn new(mut direction: Vector3, half_angle: impl Into<Degrees>) -> Self {
+		direction.normalize();
+		let half_angle = Degrees(half_angle.into().0.clamp(0.0, 180.0)).to_radians().0;
+		Cone { direction, cos_half_angle: cosf(half_angle) }
+	}
+}
+
+impl Distribution<Vector3> for Cone {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+		let local = generate::unit_vector3(1.0 - rng.random::<f32>() * (1.0 - self.cos_half_angle), rng.random());
+
+		// Orthonormal basis around the cone axis, from Duff et al. "Building an Orthonormal Basis, Revisited"
+		let n = self.direction;
+		let sign = copysignf(1.0, n.z);
+		let a = -1.0 / (sign + n.z);
+		let b = n.x * n.y * a;
+		let tangent = Vector3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x);
+		let bitangent = Vector3::new(b, sign + n.y * n.y * a, -n.y);
+		(tangent * local.x) + (bitangent * local.y) + (n * local.z)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::random::{Cone, InUnitDisk, InUnitSphere, OnUnitCircle, OnUnitSphere};
+	use crate::{Degrees, IVector2, Quaternion, Vector2, Vector3};
+	use libm::{cosf, sqrtf};
+	use rand::distr::Uniform;
+	use rand::rngs::SmallRng;
+	use rand::{Rng, SeedableRng};
+
+	#[test]
+	fn ranges() {
+		let mut rng = SmallRng::seed_from_u64(7);
+		let (min, max) = (Vector3::new(-1.0, 0.0, 10.0), Vector3::new(1.0, 0.5, 20.0));
+		let range = Uniform::new(min, max).unwrap();
+		let cells = Uniform::new_inclusive(IVector2 { x: -2, y: 3 }, IVector2 { x: 2, y: 4 }).unwrap();
+		let (mut low, mut high) = (IVector2 { x: i32::MAX, y: i32::MAX }, IVector2 { x: i32::MIN, y: i32::MIN });
+		for _ in 0..1000 {
+			let v = rng.sample(range);
+			assert!(v.x >= min.x && v.x < max.x && v.y >= min.y && v.y < max.y && v.z >= min.z && v.z < max.z);
+
+			let cell = rng.sample(cells);
+			low = low.min(cell);
+			high = high.max(cell);
+
+			let unit: Vector2 = rng.random();
+			assert!(unit.x >= 0.0 && unit.x < 1.0 && unit.y >= 0.0 && unit.y < 1.0);
+		}
+		assert_eq!((low, high), (IVector2 { x: -2, y: 3 }, IVector2 { x: 2, y: 4 }));
+		assert!(Uniform::new(Vector2::ONE, Vector2::ZERO).is_err());
+	}
+
+	#[test]
+	fn shapes() {
+		let mut rng = SmallRng::seed_from_u64(11);
+		let cone = Cone::new(Vector3::new(1.0, 1.0, -1.0), Degrees(15.0));
+		let axis = Vector3::new(1.0, 1.0, -1.0) * (1.0 / sqrtf(3.0));
+		let mut mean = Vector3::ZERO;
+		for _ in 0..1000 {
+			assert!((rng.sample(OnUnitCircle).magnitude() - 1.0).abs() < 1e-5);
+			assert!(rng.sample(InUnitDisk).magnitude() <= 1.0 + 1e-5);
+			assert!(rng.sample(InUnitSphere).magnitude() <= 1.0 + 1e-5);
+
+			let direction = rng.sample(OnUnitSphere);
+			assert!((direction.magnitude() - 1.0).abs() < 1e-5);
+			mean += direction * 0.001;
+
+			let spread = rng.sample(cone);
+			assert!((spread.magnitude() - 1.0).abs() < 1e-5);
+			let cos = spread.x * axis.x + spread.y * axis.y + spread.z * axis.z;
+			assert!(cos >= cosf(15.0f32.to_radians()) - 1e-5);
+
+			let rotation: Quaternion = rng.random();
+			assert!((rotation.magnitude() - 1.0).abs() < 1e-5);
+		}
+		assert!(mean.magnitude() < 0.1);
+	}
+}
@@ -0,0 +1,10 @@
+use libm::copysignf;
+
+/// Returns `1.0` or `-1.0` following the sign of `x`, or NaN if `x` is NaN.
+pub(crate) fn signumf(x: f32) -> f32 {
+	if x.is_nan() {
+		f32::NAN
+	} else {
+		copysignf(1.0, x)
+	}
+}
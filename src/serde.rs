@@ -0,0 +1,756 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! Every type serializes as a compact sequence: vectors, quaternions and colors as `[x, y, z]`
+//! and matrices as a flat column-major array. Human readable formats also accept the named
+//! field form (`{ "x": 1.0, "y": 2.0 }`, `{ "m00": 1.0, .. }`) and, for matrices, an array of
+//! columns, so older files still load. [`Radians`] and [`Degrees`] are a plain number.
+//!
+//! Use [`as_map`] or [`as_columns`] with `#[serde(with = "...")]` to pick another
+//! representation for a single field, and [`rotation`] or [`trs`] for hand authored rotations
+//! and transforms.
+
+use crate::{Degrees, IVector2, LinearRgba, Matrix3, Matrix4, Quaternion, Radians, Srgba, Vector2, Vector3, Vector4};
+use ::serde::de::{self, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{SerializeStruct, SerializeTuple, Serializer};
+use ::serde::{Deserialize, Serialize};
+use core::fmt;
+
+/// Serializes with named fields, `{ "x": 1.0, "y": 2.0, "z": 3.0 }` for vectors and
+/// `{ "m00": 1.0, .. "m33": 1.0 }` for [`Matrix4`]. Deserializing accepts every form.
+pub mod as_map {
+	use super::private::MapForm;
+	use ::serde::{Deserializer, Serializer};
+
+	pub fn serialize<T: MapForm, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+		value.serialize_map(serializer)
+	}
+
+	pub fn deserialize<'de, T: MapForm, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+		T::deserialize_map(deserializer)
+	}
+}
+
+/// Serializes a matrix as an array of column arrays, `[[m00, m01, m02, m03], [m10, ..], ..]`.
+/// Deserializing accepts every form.
+pub mod as_columns {
+	use super::private::ColumnsForm;
+	use ::serde::{Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<T: ColumnsForm, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+		value.serialize_columns(serializer)
+	}
+
+	pub fn deserialize<'de, T: ColumnsForm + Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+		T::deserialize(deserializer)
+	}
+}
+
+/// Reads a [`Quaternion`] from whichever form is easiest to author and normalizes it:
+///
+/// - Euler angles in degrees, as taken by [`Quaternion::from_euler_degrees`]: `{ euler_deg = [0, 90, 0] }`
+/// - A normalized axis and an angle in degrees: `{ axis = [0, 1, 0], angle_deg = 90 }`
+/// - Raw components: `{ x = 0, y = 0.7071, z = 0, w = 0.7071 }` or `[0, 0.7071, 0, 0.7071]`
+///
+/// Human readable formats are written as Euler degrees, others as raw components.
+pub mod rotation {
+	use super::RotationVisitor;
+	use crate::Quaternion;
+	use ::serde::ser::SerializeStruct;
+	use ::serde::{Deserializer, Serialize, Serializer};
+
+	pub fn serialize<S: Serializer>(value: &Quaternion, serializer: S) -> Result<S::Ok, S::Error> {
+		if serializer.is_human_readable() {
+			let mut map = serializer.serialize_struct("Rotation", 1)?;
+			map.serialize_field("euler_deg", &value.to_euler())?;
+			map.end()
+		} else {
+			value.serialize(serializer)
+		}
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Quaternion, D::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(RotationVisitor)
+		} else {
+			deserializer.deserialize_tuple(4, RotationVisitor)
+		}
+	}
+}
+
+/// Reads a [`Matrix4`] as a translation, [`rotation`] and scale, each of which may be left out:
+/// `{ translation = [0, 1, 0], rotation = { euler_deg = [0, 90, 0] }, scale = [2, 2, 2] }`.
+/// The parts are combined with [`Matrix4::from_rotation_translation_scale`]. Human readable
+/// formats also accept any of the plain [`Matrix4`] forms.
+///
+/// Writing decomposes the matrix with [`Matrix4::to_rotation_translation_scale`], so shear and
+/// projection are lost.
+pub mod trs {
+	use super::{Rotation, TrsVisitor};
+	use crate::Matrix4;
+	use ::serde::ser::{SerializeStruct, SerializeTuple};
+	use ::serde::{Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer>(value: &Matrix4, serializer: S) -> Result<S::Ok, S::Error> {
+		let (rotation, translation, scale) = value.to_rotation_translation_scale();
+		if serializer.is_human_readable() {
+			let mut map = serializer.serialize_struct("Transform", 3)?;
+			map.serialize_field("translation", &translation)?;
+			map.serialize_field("rotation", &Rotation(rotation))?;
+			map.serialize_field("scale", &scale)?;
+			map.end()
+		} else {
+			let mut tuple = serializer.serialize_tuple(3)?;
+			tuple.serialize_element(&translation)?;
+			tuple.serialize_element(&Rotation(rotation))?;
+			tuple.serialize_element(&scale)?;
+			tuple.end()
+		}
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Matrix4, D::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(TrsVisitor { human_readable: true })
+		} else {
+			deserializer.deserialize_tuple(3, TrsVisitor { human_readable: false })
+		}
+	}
+}
+
+mod private {
+	use ::serde::{Deserializer, Serializer};
+
+	pub trait MapForm: Sized {
+		fn serialize_map<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+		fn deserialize_map<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+	}
+
+	pub trait ColumnsForm {
+		fn serialize_columns<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+	}
+}
+use private::{ColumnsForm, MapForm};
+
+/// Maps a map key to its index in `fields`.
+struct FieldSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldSeed {
+	type Value = usize;
+
+	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+		deserializer.deserialize_identifier(self)
+	}
+}
+
+impl Visitor<'_> for FieldSeed {
+	type Value = usize;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a field name")
+	}
+
+	fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+		self.0.iter().position(|field| *field == value).ok_or_else(|| E::unknown_field(value, self.0))
+	}
+}
+
+macro_rules! impl_serde_vector {
+	($ty:ident, $scalar:ty, [$($field:ident),+], $build:expr) => {
+		const _: () = {
+			const NAME: &str = stringify!($ty);
+			const FIELDS: &[&str] = &[$(stringify!($field)),+];
+
+			struct VectorVisitor;
+
+			impl<'de> Visitor<'de> for VectorVisitor {
+				type Value = $ty;
+
+				fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					write!(f, "a {} as a sequence or map", NAME)
+				}
+
+				fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<$ty, A::Error> {
+					let mut index = 0;
+					$(
+						let $field: $scalar = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, &self))?;
+						index += 1;
+					)+
+					let _ = index;
+					Ok($build)
+				}
+
+				fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<$ty, A::Error> {
+					let mut values: [Option<$scalar>; FIELDS.len()] = [None; FIELDS.len()];
+					while let Some(index) = map.next_key_seed(FieldSeed(FIELDS))? {
+						if values[index].is_some() {
+							return Err(de::Error::duplicate_field(FIELDS[index]));
+						}
+						values[index] = Some(map.next_value()?);
+					}
+					let [$($field),+] = values;
+					$(
+						let $field = $field.ok_or_else(|| de::Error::missing_field(stringify!($field)))?;
+					)+
+					Ok($build)
+				}
+			}
+
+			impl Serialize for $ty {
+				fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+					let mut tuple = serializer.serialize_tuple(FIELDS.len())?;
+					$(tuple.serialize_element(&self.$field)?;)+
+					tuple.end()
+				}
+			}
+
+			impl<'de> Deserialize<'de> for $ty {
+				fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+					if deserializer.is_human_readable() {
+						deserializer.deserialize_any(VectorVisitor)
+					} else {
+						deserializer.deserialize_tuple(FIELDS.len(), VectorVisitor)
+					}
+				}
+			}
+
+			impl MapForm for $ty {
+				fn serialize_map<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+					let mut map = serializer.serialize_struct(NAME, FIELDS.len())?;
+					$(map.serialize_field(stringify!($field), &self.$field)?;)+
+					map.end()
+				}
+
+				fn deserialize_map<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+					deserializer.deserialize_struct(NAME, FIELDS, VectorVisitor)
+				}
+			}
+		};
+	};
+}
+
+impl_serde_vector!(Vector2, f32, [x, y], Vector2 { x, y });
+impl_serde_vector!(Vector3, f32, [x, y, z], Vector3::new(x, y, z));
+impl_serde_vector!(Vector4, f32, [x, y, z, w], Vector4 { x, y, z, w });
+impl_serde_vector!(IVector2, i32, [x, y], IVector2 { x, y });
+impl_serde_vector!(Quaternion, f32, [x, y, z, w], Quaternion { x, y, z, w });
+impl_serde_vector!(LinearRgba, f32, [r, g, b, a], LinearRgba { r, g, b, a });
+impl_serde_vector!(Srgba, f32, [r, g, b, a], Srgba { r, g, b, a });
+
+macro_rules! impl_serde_angle {
+	($ty:ident) => {
+		impl Serialize for $ty {
+			fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_f32(self.0)
+			}
+		}
+
+		impl<'de> Deserialize<'de> for $ty {
+			fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				f32::deserialize(deserializer).map($ty)
+			}
+		}
+	};
+}
+
+impl_serde_angle!(Radians);
+impl_serde_angle!(Degrees);
+
+/// A matrix sequence element, either a number of a flat array or a whole column.
+enum Element {
+	Scalar(f32),
+	Column([f32; 4], usize),
+}
+
+impl<'de> Deserialize<'de> for Element {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_any(ElementVisitor)
+	}
+}
+
+struct ElementVisitor;
+
+impl<'de> Visitor<'de> for ElementVisitor {
+	type Value = Element;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a number or a column array")
+	}
+
+	fn visit_f64<E: de::Error>(self, value: f64) -> Result<Element, E> {
+		Ok(Element::Scalar(value as f32))
+	}
+
+	fn visit_i64<E: de::Error>(self, value: i64) -> Result<Element, E> {
+		Ok(Element::Scalar(value as f32))
+	}
+
+	fn visit_u64<E: de::Error>(self, value: u64) -> Result<Element, E> {
+		Ok(Element::Scalar(value as f32))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Element, A::Error> {
+		let mut column = [0.0; 4];
+		let mut len = 0;
+		while let Some(value) = seq.next_element()? {
+			if len == column.len() {
+				return Err(de::Error::invalid_length(len + 1, &self));
+			}
+			column[len] = value;
+			len += 1;
+		}
+		Ok(Element::Column(column, len))
+	}
+}
+
+/// Reads a `size` x `size` matrix in column-major order from either a flat sequence or a
+/// sequence of columns. Non self-describing formats only support the flat form.
+fn visit_matrix_seq<'de, A: SeqAccess<'de>>(mut seq: A, size: usize, flat_only: bool, expected: &dyn de::Expected) -> Result<[f32; 16], A::Error> {
+	let mut values = [0.0; 16];
+	let first = if flat_only {
+		seq.next_element()?.map(Element::Scalar)
+	} else {
+		seq.next_element()?
+	};
+
+	match first.ok_or_else(|| de::Error::invalid_length(0, expected))? {
+		Element::Scalar(value) => {
+			values[0] = value;
+			for (index, value) in values.iter_mut().enumerate().take(size * size).skip(1) {
+				*value = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, expected))?;
+			}
+		}
+		Element::Column(column, len) => {
+			let mut next = Some(Element::Column(column, len));
+			for index in 0..size {
+				let element = match next.take() {
+					Some(element) => element,
+					None => seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, expected))?,
+				};
+				match element {
+					Element::Column(column, len) if len == size => {
+						values[(index * size)..((index + 1) * size)].copy_from_slice(&column[..size]);
+					}
+					Element::Column(_, len) => return Err(de::Error::invalid_length(len, &"a full column")),
+					Element::Scalar(_) => return Err(de::Error::custom("cannot mix numbers and columns in a matrix")),
+				}
+			}
+		}
+	}
+	Ok(values)
+}
+
+const MATRIX4_FIELDS: &[&str] = &[
+	"m00", "m01", "m02", "m03",
+	"m10", "m11", "m12", "m13",
+	"m20", "m21", "m22", "m23",
+	"m30", "m31", "m32", "m33",
+];
+
+struct Matrix4Visitor {
+	flat_only: bool,
+}
+
+impl<'de> Visitor<'de> for Matrix4Visitor {
+	type Value = Matrix4;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a Matrix4 as 16 numbers, 4 columns or a map")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Matrix4, A::Error> {
+		visit_matrix_seq(seq, 4, self.flat_only, &self).map(Matrix4::from_cols_array)
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Matrix4, A::Error> {
+		let mut values = [None; 16];
+		while let Some(index) = map.next_key_seed(FieldSeed(MATRIX4_FIELDS))? {
+			if values[index].is_some() {
+				return Err(de::Error::duplicate_field(MATRIX4_FIELDS[index]));
+			}
+			values[index] = Some(map.next_value()?);
+		}
+		let mut array = [0.0; 16];
+		for (index, value) in values.into_iter().enumerate() {
+			array[index] = value.ok_or_else(|| de::Error::missing_field(MATRIX4_FIELDS[index]))?;
+		}
+		Ok(Matrix4::from_cols_array(array))
+	}
+}
+
+impl Serialize for Matrix4 {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.to_cols_array().serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Matrix4 {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(Matrix4Visitor { flat_only: false })
+		} else {
+			deserializer.deserialize_tuple(16, Matrix4Visitor { flat_only: true })
+		}
+	}
+}
+
+impl MapForm for Matrix4 {
+	fn serialize_map<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut map = serializer.serialize_struct("Matrix4", 16)?;
+		for (field, value) in MATRIX4_FIELDS.iter().zip(self.to_cols_array()) {
+			map.serialize_field(field, &value)?;
+		}
+		map.end()
+	}
+
+	fn deserialize_map<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let flat_only = !deserializer.is_human_readable();
+		deserializer.deserialize_struct("Matrix4", MATRIX4_FIELDS, Matrix4Visitor { flat_only })
+	}
+}
+
+impl ColumnsForm for Matrix4 {
+	fn serialize_columns<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.to_cols_array_2d().serialize(serializer)
+	}
+}
+
+const MATRIX3_FIELDS: &[&str] = &["x", "y", "z"];
+
+struct Matrix3Visitor {
+	flat_only: bool,
+}
+
+impl<'de> Visitor<'de> for Matrix3Visitor {
+	type Value = Matrix3;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a Matrix3 as 9 numbers, 3 columns or a map")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Matrix3, A::Error> {
+		let m = visit_matrix_seq(seq, 3, self.flat_only, &self)?;
+		Ok(Matrix3 {
+			x: Vector3::new(m[0], m[1], m[2]),
+			y: Vector3::new(m[3], m[4], m[5]),
+			z: Vector3::new(m[6], m[7], m[8]),
+		})
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Matrix3, A::Error> {
+		let mut columns: [Option<Vector3>; 3] = [None; 3];
+		while let Some(index) = map.next_key_seed(FieldSeed(MATRIX3_FIELDS))? {
+			if columns[index].is_some() {
+				return Err(de::Error::duplicate_field(MATRIX3_FIELDS[index]));
+			}
+			columns[index] = Some(map.next_value()?);
+		}
+		let [x, y, z] = columns;
+		Ok(Matrix3 {
+			x: x.ok_or_else(|| de::Error::missing_field("x"))?,
+			y: y.ok_or_else(|| de::Error::missing_field("y"))?,
+			z: z.ok_or_else(|| de::Error::missing_field("z"))?,
+		})
+	}
+}
+
+impl Serialize for Matrix3 {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.to_cols_array().serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Matrix3 {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_any(Matrix3Visitor { flat_only: false })
+		} else {
+			deserializer.deserialize_tuple(9, Matrix3Visitor { flat_only: true })
+		}
+	}
+}
+
+/// The named form keeps the columns as nested vectors, `{ "x": [1, 0, 0], .. }`.
+impl MapForm for Matrix3 {
+	fn serialize_map<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut map = serializer.serialize_struct("Matrix3", 3)?;
+		map.serialize_field("x", &self.x)?;
+		map.serialize_field("y", &self.y)?;
+		map.serialize_field("z", &self.z)?;
+		map.end()
+	}
+
+	fn deserialize_map<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let flat_only = !deserializer.is_human_readable();
+		deserializer.deserialize_struct("Matrix3", MATRIX3_FIELDS, Matrix3Visitor { flat_only })
+	}
+}
+
+impl ColumnsForm for Matrix3 {
+	fn serialize_columns<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut tuple = serializer.serialize_tuple(3)?;
+		tuple.serialize_element(&self.x)?;
+		tuple.serialize_element(&self.y)?;
+		tuple.serialize_element(&self.z)?;
+		tuple.end()
+	}
+}
+
+/// Scales `rotation` to unit length, rejecting values that have no direction.
+fn normalized<E: de::Error>(mut rotation: Quaternion) -> Result<Quaternion, E> {
+	let magnitude = rotation.magnitude();
+	if !magnitude.is_finite() || magnitude < f32::EPSILON {
+		return Err(E::custom("rotation must have a non-zero, finite length"));
+	}
+	rotation.normalize();
+	Ok(rotation)
+}
+
+const ROTATION_FIELDS: &[&str] = &["euler_deg", "axis", "angle_deg", "x", "y", "z", "w"];
+
+struct RotationVisitor;
+
+impl<'de> Visitor<'de> for RotationVisitor {
+	type Value = Quaternion;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a rotation as `euler_deg`, `axis` and `angle_deg`, or `x`, `y`, `z` and `w`")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Quaternion, A::Error> {
+		let mut components = [0.0; 4];
+		for (index, component) in components.iter_mut().enumerate() {
+			*component = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(index, &self))?;
+		}
+		let [x, y, z, w] = components;
+		normalized(Quaternion { x, y, z, w })
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Quaternion, A::Error> {
+		let mut euler: Option<Vector3> = None;
+		let mut axis: Option<Vector3> = None;
+		let mut angle: Option<f32> = None;
+		let mut components: [Option<f32>; 4] = [None; 4];
+		let mut seen = [false; 7];
+		while let Some(index) = map.next_key_seed(FieldSeed(ROTATION_FIELDS))? {
+			if seen[index] {
+				return Err(de::Error::duplicate_field(ROTATION_FIELDS[index]));
+			}
+			seen[index] = true;
+			match index {
+				0 => euler = Some(map.next_value()?),
+				1 => axis = Some(map.next_value()?),
+				2 => angle = Some(map.next_value()?),
+				_ => components[index - 3] = Some(map.next_value()?),
+			}
+		}
+
+		let raw = components.iter().any(Option::is_some);
+		match (euler, axis, angle, raw) {
+			(Some(euler), None, None, false) => normalized(Quaternion::from_euler_degrees(euler)),
+			(None, Some(mut axis), Some(angle), false) => {
+				if axis.magnitude() < f32::EPSILON {
+					return Err(de::Error::custom("rotation axis must have a non-zero length"));
+				}
+				axis.normalize();
+				normalized(Quaternion::from_axis_angle_degrees(axis, angle))
+			}
+			(None, None, None, true) => {
+				let [x, y, z, w] = components;
+				normalized(Quaternion {
+					x: x.ok_or_else(|| de::Error::missing_field("x"))?,
+					y: y.ok_or_else(|| de::Error::missing_field("y"))?,
+					z: z.ok_or_else(|| de::Error::missing_field("z"))?,
+					w: w.ok_or_else(|| de::Error::missing_field("w"))?,
+				})
+			}
+			(None, Some(_), None, false) => Err(de::Error::missing_field("angle_deg")),
+			(None, None, Some(_), false) => Err(de::Error::missing_field("axis")),
+			_ => Err(de::Error::custom("a rotation takes exactly one of `euler_deg`, `axis` with `angle_deg`, or `x`, `y`, `z`, `w`")),
+		}
+	}
+}
+
+/// A [`Quaternion`] that goes through the [`rotation`] adapter.
+struct Rotation(Quaternion);
+
+impl Serialize for Rotation {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		rotation::serialize(&self.0, serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Rotation {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		rotation::deserialize(deserializer).map(Rotation)
+	}
+}
+
+const TRS_FIELDS: &[&str] = &["translation", "rotation", "scale"];
+
+struct TrsVisitor {
+	human_readable: bool,
+}
+
+impl<'de> Visitor<'de> for TrsVisitor {
+	type Value = Matrix4;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a transform with `translation`, `rotation` and `scale`")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Matrix4, A::Error> {
+		if self.human_readable {
+			return visit_matrix_seq(seq, 4, false, &self).map(Matrix4::from_cols_array);
+		}
+		let translation = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+		let Rotation(rotation) = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+		let scale = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+		Ok(Matrix4::from_rotation_translation_scale(rotation, translation, scale))
+	}
+
+	fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Matrix4, A::Error> {
+		let mut translation: Option<Vector3> = None;
+		let mut rotation: Option<Quaternion> = None;
+		let mut scale: Option<Vector3> = None;
+		while let Some(index) = map.next_key_seed(FieldSeed(TRS_FIELDS))? {
+			let duplicate = match index {
+				0 => translation.replace(map.next_value()?).is_some(),
+				1 => rotation.replace(map.next_value::<Rotation>()?.0).is_some(),
+				_ => scale.replace(map.next_value()?).is_some(),
+			};
+			if duplicate {
+				return Err(de::Error::duplicate_field(TRS_FIELDS[index]));
+			}
+		}
+		Ok(Matrix4::from_rotation_translation_scale(
+			rotation.unwrap_or(Quaternion::IDENTITY),
+			translation.unwrap_or(Vector3::ZERO),
+			scale.unwrap_or(Vector3::ONE),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate std;
+
+	use crate::{Degrees, IVector2, Matrix3, Matrix4, Quaternion, Radians, Srgba, Vector2, Vector3};
+	use serde_json::{from_str, json, to_string, to_value};
+
+	#[derive(serde::Serialize, serde::Deserialize, Debug)]
+	struct Prop {
+		#[serde(with = "crate::serde::rotation")]
+		rotation: Quaternion,
+		#[serde(with = "crate::serde::trs")]
+		transform: Matrix4,
+	}
+
+	fn same_rotation(a: Quaternion, b: Quaternion) -> bool {
+		let dot = a.x * b.x + a.y * b.y + a.z * b.z + a.w * b.w;
+		(dot.abs() - 1.0).abs() < 1e-5
+	}
+
+	fn same_matrix(a: Matrix4, b: Matrix4) -> bool {
+		let (a, b) = (a.to_cols_array(), b.to_cols_array());
+		a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-5)
+	}
+
+	#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+	struct Node {
+		position: Vector3,
+		#[serde(with = "crate::serde::as_map")]
+		velocity: Vector3,
+		#[serde(with = "crate::serde::as_columns")]
+		transform: Matrix4,
+		#[serde(with = "crate::serde::as_map")]
+		basis: Matrix3,
+	}
+
+	#[test]
+	fn compact() {
+		assert_eq!(to_string(&Vector3::new(1.0, 2.0, 3.0)).unwrap(), "[1.0,2.0,3.0]");
+		assert_eq!(to_string(&IVector2 { x: -1, y: 2 }).unwrap(), "[-1,2]");
+		assert_eq!(to_value(Quaternion::IDENTITY).unwrap(), json!([0.0, 0.0, 0.0, 1.0]));
+		assert_eq!(to_value(Srgba::RED).unwrap(), json!([1.0, 0.0, 0.0, 1.0]));
+		assert_eq!(to_string(&Degrees(90.0)).unwrap(), "90.0");
+		assert_eq!(from_str::<Radians>("1.5").unwrap(), Radians(1.5));
+		assert_eq!(to_value(Matrix4::IDENTITY).unwrap(), json!([1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]));
+	}
+
+	#[test]
+	fn accepts_every_form() {
+		assert_eq!(from_str::<Vector2>("[1, 2]").unwrap(), Vector2 { x: 1.0, y: 2.0 });
+		assert_eq!(from_str::<Vector2>(r#"{"y": 2, "x": 1}"#).unwrap(), Vector2 { x: 1.0, y: 2.0 });
+		assert!(from_str::<Vector2>(r#"{"x": 1}"#).is_err());
+		assert!(from_str::<Vector2>(r#"{"x": 1, "y": 2, "w": 3}"#).is_err());
+		assert!(from_str::<Vector2>("[1, 2, 3]").is_err());
+
+		let translation = Matrix4::IDENTITY.translate(Vector3::new(4.0, 5.0, 6.0));
+		let flat = "[1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 4, 5, 6, 1]";
+		let columns = "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [4, 5, 6, 1]]";
+		let map = to_string(&to_value(crate::serde::as_map::serialize(&translation, serde_json::value::Serializer).unwrap()).unwrap()).unwrap();
+		assert_eq!(from_str::<Matrix4>(flat).unwrap(), translation);
+		assert_eq!(from_str::<Matrix4>(columns).unwrap(), translation);
+		assert_eq!(from_str::<Matrix4>(&map).unwrap(), translation);
+		assert!(from_str::<Matrix4>("[[1, 0, 0, 0], 1]").is_err());
+		assert!(from_str::<Matrix4>("[[1, 0, 0], [0, 1, 0], [0, 0, 1], [0, 0, 0]]").is_err());
+
+		let basis = Matrix3::from_translation(Vector2 { x: 4.0, y: 5.0 });
+		assert_eq!(from_str::<Matrix3>("[1, 0, 0, 0, 1, 0, 4, 5, 1]").unwrap(), basis);
+		assert_eq!(from_str::<Matrix3>("[[1, 0, 0], [0, 1, 0], [4, 5, 1]]").unwrap(), basis);
+		assert_eq!(from_str::<Matrix3>(r#"{"x": {"x": 1, "y": 0, "z": 0}, "y": [0, 1, 0], "z": [4, 5, 1]}"#).unwrap(), basis);
+	}
+
+	#[test]
+	fn with_helpers() {
+		let node = Node {
+			position: Vector3::new(1.0, 2.0, 3.0),
+			velocity: Vector3::UP,
+			transform: Matrix4::IDENTITY,
+			basis: Matrix3::IDENTITY,
+		};
+		let value = to_value(&node).unwrap();
+		assert_eq!(value["velocity"], json!({ "x": 0.0, "y": 1.0, "z": 0.0 }));
+		assert_eq!(value["transform"][3], json!([0.0, 0.0, 0.0, 1.0]));
+		assert_eq!(value["basis"]["z"], json!([0.0, 0.0, 1.0]));
+		assert_eq!(serde_json::from_value::<Node>(value).unwrap(), node);
+	}
+
+	#[test]
+	fn rotation_forms() {
+		let quarter_turn = Quaternion::from_axis_angle_degrees(Vector3::UP, 90.0);
+		let prop: Prop = toml::from_str("rotation = { euler_deg = [0, 90, 0] }\ntransform = {}").unwrap();
+		assert!(same_rotation(prop.rotation, quarter_turn));
+		assert_eq!(prop.transform, Matrix4::IDENTITY);
+
+		let read = |rotation: &str| toml::from_str::<Prop>(&std::format!("rotation = {}\ntransform = {{}}", rotation)).map(|prop| prop.rotation);
+		assert!(same_rotation(read("{ axis = [0, 2, 0], angle_deg = 90 }").unwrap(), quarter_turn));
+		assert!(same_rotation(read("{ x = 0, y = 3, z = 0, w = 3 }").unwrap(), quarter_turn));
+		assert!(same_rotation(read("[0, 1, 0, 1]").unwrap(), quarter_turn));
+		assert!((read("[0, 1, 0, 1]").unwrap().magnitude() - 1.0).abs() < 1e-6);
+		assert!(read("[0, 0, 0, 0]").is_err());
+		assert!(read("{ axis = [0, 1, 0] }").is_err());
+		assert!(read("{ euler_deg = [0, 90, 0], w = 1 }").is_err());
+		assert!(read("{ x = 0, y = 0, z = 0 }").is_err());
+	}
+
+	#[test]
+	fn trs() {
+		let expected = Matrix4::from_rotation_translation_scale(Quaternion::from_euler_degrees(Vector3::new(30.0, 45.0, -60.0)), Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 0.5, 1.0));
+		let prop: Prop = toml::from_str(
+			"rotation = [0, 0, 0, 1]\n[transform]\ntranslation = [1, 2, 3]\nrotation = { euler_deg = [30, 45, -60] }\nscale = [2, 0.5, 1]",
+		).unwrap();
+		assert!(same_matrix(prop.transform, expected));
+
+		let written = toml::to_string(&prop).unwrap();
+		let reread: Prop = toml::from_str(&written).unwrap();
+		assert!(same_rotation(reread.rotation, Quaternion::IDENTITY));
+		assert!(same_matrix(reread.transform, expected));
+
+		let mirrored = Matrix4::from_rotation_translation_scale(Quaternion::from_euler_degrees(Vector3::new(0.0, 170.0, 10.0)), Vector3::ZERO, Vector3::new(-1.0, 3.0, 2.0));
+		let value = to_value(Prop { rotation: Quaternion::IDENTITY, transform: mirrored }).unwrap();
+		assert!(same_matrix(serde_json::from_value::<Prop>(value).unwrap().transform, mirrored));
+
+		let flat: Prop = from_str(r#"{ "rotation": [0, 0, 0, 1], "transform": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 4, 5, 6, 1] }"#).unwrap();
+		assert_eq!(flat.transform, Matrix4::IDENTITY.translate(Vector3::new(4.0, 5.0, 6.0)));
+	}
+}
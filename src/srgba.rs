@@ -0,0 +1,292 @@
+use crate::{LinearRgba, Vector3, Vector4};
+use core::fmt;
+use libm::{fabsf, fmaxf, fminf, fmodf, powf, roundf};
+
+/// A gamma encoded sRGB color with straight (non-premultiplied) alpha.
+/// This is the representation used by color pickers, hex codes and 8-bit textures, convert to
+/// [`LinearRgba`] before doing any arithmetic.
+/// This struct is [repr(C)] with an alignment of 16 to satisfy std140
+#[repr(C, align(16))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Srgba {
+	pub r: f32,
+	pub g: f32,
+	pub b: f32,
+	pub a: f32,
+}
+
+impl Srgba {
+	pub const TRANSPARENT: Self = Srgba::rgba(0.0, 0.0, 0.0, 0.0);
+	pub const BLACK: Self = Srgba::rgb(0.0, 0.0, 0.0);
+	pub const WHITE: Self = Srgba::rgb(1.0, 1.0, 1.0);
+	pub const GREY: Self = Srgba::rgb(0.5, 0.5, 0.5);
+	pub const RED: Self = Srgba::rgb(1.0, 0.0, 0.0);
+	pub const GREEN: Self = Srgba::rgb(0.0, 1.0, 0.0);
+	pub const BLUE: Self = Srgba::rgb(0.0, 0.0, 1.0);
+	pub const YELLOW: Self = Srgba::rgb(1.0, 1.0, 0.0);
+	pub const CYAN: Self = Srgba::rgb(0.0, 1.0, 1.0);
+	pub const MAGENTA: Self = Srgba::rgb(1.0, 0.0, 1.0);
+
+	pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+		Srgba { r, g, b, a: 1.0 }
+	}
+
+	pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+		Srgba { r, g, b, a }
+	}
+
+	pub const fn with_alpha(self, a: f32) -> Self {
+		Srgba { a, ..self }
+	}
+
+	/// Decodes into linear space, alpha is passed through unchanged.
+	pub fn to_linear(&self) -> LinearRgba {
+		LinearRgba {
+			r: srgb_to_linear(self.r),
+			g: srgb_to_linear(self.g),
+			b: srgb_to_linear(self.b),
+			a: self.a,
+		}
+	}
+
+	pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+		Srgba {
+			r: r as f32 / 255.0,
+			g: g as f32 / 255.0,
+			b: b as f32 / 255.0,
+			a: a as f32 / 255.0,
+		}
+	}
+
+	/// Quantizes each channel to 8 bits, values outside `0.0..=1.0` are clamped.
+	pub fn to_rgba8(&self) -> [u8; 4] {
+		[to_u8(self.r), to_u8(self.g), to_u8(self.b), to_u8(self.a)]
+	}
+
+	/// Unpacks a `0xRRGGBBAA` value.
+	pub fn from_u32(rgba: u32) -> Self {
+		let [r, g, b, a] = rgba.to_be_bytes();
+		Srgba::from_rgba8(r, g, b, a)
+	}
+
+	/// Packs into a `0xRRGGBBAA` value.
+	pub fn to_u32(&self) -> u32 {
+		u32::from_be_bytes(self.to_rgba8())
+	}
+
+	/// Parses `RGB`, `RGBA`, `RRGGBB` or `RRGGBBAA` hex digits with an optional leading `#`.
+	pub fn from_hex(hex: &str) -> Result<Self, HexParseError> {
+		let hex = hex.strip_prefix('#').unwrap_or(hex);
+		let mut digits = [0u8; 8];
+		if hex.len() > digits.len() {
+			return Err(HexParseError::InvalidLength);
+		}
+		for (digit, char) in digits.iter_mut().zip(hex.chars()) {
+			*digit = char.to_digit(16).ok_or(HexParseError::InvalidDigit(char))? as u8;
+		}
+
+		let [r, g, b, a] = match hex.len() {
+			3 => [digits[0] * 17, digits[1] * 17, digits[2] * 17, 255],
+			4 => [digits[0] * 17, digits[1] * 17, digits[2] * 17, digits[3] * 17],
+			6 => [(digits[0] << 4) | digits[1], (digits[2] << 4) | digits[3], (digits[4] << 4) | digits[5], 255],
+			8 => [(digits[0] << 4) | digits[1], (digits[2] << 4) | digits[3], (digits[4] << 4) | digits[5], (digits[6] << 4) | digits[7]],
+			_ => return Err(HexParseError::InvalidLength),
+		};
+		Ok(Srgba::from_rgba8(r, g, b, a))
+	}
+
+	/// Builds a color from hue in degrees, saturation and value in `0.0..=1.0`.
+	pub fn from_hsv(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+		let chroma = value * saturation;
+		let (r, g, b) = from_hue_chroma(hue, chroma);
+		let m = value - chroma;
+		Srgba { r: r + m, g: g + m, b: b + m, a: alpha }
+	}
+
+	/// Returns `(hue, saturation, value)` with hue in degrees `0.0..360.0`.
+	pub fn to_hsv(&self) -> (f32, f32, f32) {
+		let max = fmaxf(fmaxf(self.r, self.g), self.b);
+		let min = fminf(fminf(self.r, self.g), self.b);
+		let chroma = max - min;
+		let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+		(self.hue(max, chroma), saturation, max)
+	}
+
+	/// Builds a color from hue in degrees, saturation and lightness in `0.0..=1.0`.
+	pub fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+		let chroma = (1.0 - fabsf((2.0 * lightness) - 1.0)) * saturation;
+		let (r, g, b) = from_hue_chroma(hue, chroma);
+		let m = lightness - (chroma / 2.0);
+		Srgba { r: r + m, g: g + m, b: b + m, a: alpha }
+	}
+
+	/// Returns `(hue, saturation, lightness)` with hue in degrees `0.0..360.0`.
+	pub fn to_hsl(&self) -> (f32, f32, f32) {
+		let max = fmaxf(fmaxf(self.r, self.g), self.b);
+		let min = fminf(fminf(self.r, self.g), self.b);
+		let chroma = max - min;
+		let lightness = (max + min) / 2.0;
+		let saturation = if chroma == 0.0 { 0.0 } else { chroma / (1.0 - fabsf((2.0 * lightness) - 1.0)) };
+		(self.hue(max, chroma), saturation, lightness)
+	}
+
+	fn hue(&self, max: f32, chroma: f32) -> f32 {
+		if chroma == 0.0 {
+			return 0.0;
+		}
+		let sector = if max == self.r {
+			fmodf((self.g - self.b) / chroma, 6.0)
+		} else if max == self.g {
+			((self.b - self.r) / chroma) + 2.0
+		} else {
+			((self.r - self.g) / chroma) + 4.0
+		};
+		let hue = sector * 60.0;
+		if hue < 0.0 { hue + 360.0 } else { hue }
+	}
+}
+
+/// The sRGB electro-optical transfer function, decoding a gamma encoded channel into linear space.
+pub fn srgb_to_linear(value: f32) -> f32 {
+	if value <= 0.04045 {
+		value / 12.92
+	} else {
+		powf((value + 0.055) / 1.055, 2.4)
+	}
+}
+
+/// The inverse of [`srgb_to_linear`], encoding a linear channel for display.
+pub fn linear_to_srgb(value: f32) -> f32 {
+	if value <= 0.0031308 {
+		value * 12.92
+	} else {
+		(1.055 * powf(value, 1.0 / 2.4)) - 0.055
+	}
+}
+
+fn to_u8(value: f32) -> u8 {
+	roundf(fminf(fmaxf(value, 0.0), 1.0) * 255.0) as u8
+}
+
+fn from_hue_chroma(hue: f32, chroma: f32) -> (f32, f32, f32) {
+	let sector = fmodf(fmodf(hue, 360.0) + 360.0, 360.0) / 60.0;
+	let x = chroma * (1.0 - fabsf(fmodf(sector, 2.0) - 1.0));
+	match sector as u32 {
+		0 => (chroma, x, 0.0),
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		_ => (chroma, 0.0, x),
+	}
+}
+
+impl From<LinearRgba> for Srgba {
+	fn from(value: LinearRgba) -> Self {
+		value.to_srgb()
+	}
+}
+
+impl From<Vector4> for Srgba {
+	fn from(value: Vector4) -> Self {
+		Srgba { r: value.x, g: value.y, b: value.z, a: value.w }
+	}
+}
+
+impl From<Srgba> for Vector4 {
+	fn from(value: Srgba) -> Self {
+		Vector4 { x: value.r, y: value.g, z: value.b, w: value.a }
+	}
+}
+
+/// Opaque color from `x`, `y`, `z` as red, green and blue.
+impl From<Vector3> for Srgba {
+	fn from(value: Vector3) -> Self {
+		Srgba::rgb(value.x, value.y, value.z)
+	}
+}
+
+/// Drops the alpha channel.
+impl From<Srgba> for Vector3 {
+	fn from(value: Srgba) -> Self {
+		Vector3::new(value.r, value.g, value.b)
+	}
+}
+
+/// Formats as `rrggbbaa`, or `#rrggbbaa` with the alternate flag.
+impl fmt::LowerHex for Srgba {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			f.write_str("#")?;
+		}
+		write!(f, "{:08x}", self.to_u32())
+	}
+}
+
+/// Formats as `RRGGBBAA`, or `#RRGGBBAA` with the alternate flag.
+impl fmt::UpperHex for Srgba {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			f.write_str("#")?;
+		}
+		write!(f, "{:08X}", self.to_u32())
+	}
+}
+
+/// Error returned by [`Srgba::from_hex`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HexParseError {
+	/// The string was not 3, 4, 6 or 8 hex digits long.
+	InvalidLength,
+	/// The string contained a character that is not a hex digit.
+	InvalidDigit(char),
+}
+
+impl fmt::Display for HexParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			HexParseError::InvalidLength => f.write_str("hex color must have 3, 4, 6 or 8 digits"),
+			HexParseError::InvalidDigit(char) => write!(f, "invalid hex digit {char:?}"),
+		}
+	}
+}
+
+impl core::error::Error for HexParseError {}
+
+#[cfg(test)]
+mod tests {
+	use crate::{HexParseError, LinearRgba, Srgba};
+
+	#[test]
+	fn transfer() {
+		for i in 0..=255u8 {
+			let color = Srgba::from_rgba8(i, i, i, i);
+			assert_eq!(color.to_linear().to_srgb().to_rgba8(), [i, i, i, i]);
+		}
+		assert_eq!(Srgba::WHITE.to_linear(), LinearRgba::WHITE);
+		assert!((Srgba::GREY.to_linear().r - 0.214041).abs() < 1e-6);
+	}
+
+	#[test]
+	fn packing() {
+		let color = Srgba::from_u32(0x336699cc);
+		assert_eq!(color.to_u32(), 0x336699cc);
+		assert_eq!(Srgba::from_hex("#369c"), Ok(color));
+		assert_eq!(Srgba::from_hex("336699cc"), Ok(color));
+		assert_eq!(Srgba::from_hex("#336699"), Ok(color.with_alpha(1.0)));
+		assert_eq!(Srgba::from_hex("#33669"), Err(HexParseError::InvalidLength));
+		assert_eq!(Srgba::from_hex("#33669g"), Err(HexParseError::InvalidDigit('g')));
+	}
+
+	#[test]
+	fn hsv_hsl() {
+		let color = Srgba::from_rgba8(51, 102, 153, 255);
+		let (h, s, v) = color.to_hsv();
+		assert_eq!(Srgba::from_hsv(h, s, v, 1.0).to_rgba8(), color.to_rgba8());
+		let (h, s, l) = color.to_hsl();
+		assert_eq!(Srgba::from_hsl(h, s, l, 1.0).to_rgba8(), color.to_rgba8());
+		assert_eq!(Srgba::from_hsv(120.0, 1.0, 1.0, 1.0), Srgba::GREEN);
+		assert_eq!(Srgba::MAGENTA.to_hsl(), (300.0, 1.0, 0.5));
+	}
+}
@@ -0,0 +1,66 @@
+use crate::{Matrix4, Quaternion, Vector3};
+
+/// A translation, rotation and scale, applied as scale first, then rotation, then translation.
+/// The decomposed counterpart of [`Matrix4::from_rotation_translation_scale`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Transform {
+	pub translation: Vector3,
+	pub rotation: Quaternion,
+	pub scale: Vector3,
+}
+
+impl Transform {
+	pub const IDENTITY: Transform = Transform { translation: Vector3::ZERO, rotation: Quaternion::IDENTITY, scale: Vector3::ONE };
+
+	pub const fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Self {
+		Transform { translation, rotation, scale }
+	}
+
+	pub const fn from_translation(translation: Vector3) -> Self {
+		Transform { translation, rotation: Quaternion::IDENTITY, scale: Vector3::ONE }
+	}
+
+	pub const fn from_rotation_translation(rotation: Quaternion, translation: Vector3) -> Self {
+		Transform { translation, rotation, scale: Vector3::ONE }
+	}
+
+	pub const fn transform_point(&self, point: Vector3) -> Vector3 {
+		self.transform_vector(point).add(self.translation)
+	}
+
+	/// Scales and rotates `vector`, ignoring the translation.
+	pub const fn transform_vector(&self, vector: Vector3) -> Vector3 {
+		self.rotation.rotate(Vector3::new(vector.x * self.scale.x, vector.y * self.scale.y, vector.z * self.scale.z))
+	}
+
+	pub const fn to_matrix(&self) -> Matrix4 {
+		Matrix4::from_rotation_translation_scale(self.rotation, self.translation, self.scale)
+	}
+}
+
+impl From<Transform> for Matrix4 {
+	fn from(value: Transform) -> Self {
+		value.to_matrix()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Quaternion, Transform, Vector3};
+
+	#[test]
+	fn matches_matrix() {
+		let transform = Transform::new(
+			Vector3::new(3.0, -1.0, 2.0),
+			Quaternion::from_euler_degrees(Vector3::new(20.0, -70.0, 45.0)),
+			Vector3::new(2.0, 0.5, -1.5),
+		);
+		let point = Vector3::new(0.25, 4.0, -3.0);
+		let expected = transform.to_matrix().transform_point(point);
+		assert!((transform.transform_point(point) - expected).magnitude() < 1e-5);
+		assert_eq!(Transform::IDENTITY.transform_point(point), point);
+		assert_eq!(Transform::from_translation(Vector3::ONE).transform_vector(point), point);
+	}
+}
@@ -0,0 +1,313 @@
+//! Generators shared by the vector types, so every dimension and scalar gets the same methods.
+//!
+//! Each type keeps its own struct, which fixes the field names and std140 alignment, along with
+//! its `new` constructor and anything specific to its dimension or scalar such as
+//! [`Vector3::cross`](crate::Vector3::cross). Generated code only builds values through `new`.
+
+/// Constants, constant arithmetic and operators for a vector of any scalar.
+///
+/// Primitive scalars take `(Vector2, f32, 0.0, 1.0, [x, y])`. Fixed-point vectors, which are generic
+/// over their [`FixedPoint`](crate::FixedPoint) scalar, take `(FixedVector2<T>, [Fixed32, Fixed64],
+/// [x, y])`: the constants, magnitude and operators stay generic while the constant arithmetic is
+/// generated for each listed scalar, since trait operators cannot be called in constant expressions.
+macro_rules! impl_vector {
+	($ty:ident, $scalar:ty, $zero:literal, $one:literal, [$first:ident $(, $rest:ident)*]) => {
+		impl $ty {
+			pub const ZERO: $ty = $ty::splat($zero);
+			pub const ONE: $ty = $ty::splat($one);
+
+			pub const fn splat(value: $scalar) -> Self {
+				$ty::new(value $(, impl_vector!(@same $rest value))*)
+			}
+		}
+
+		impl_vector!(@arithmetic native, $ty, $scalar, [$first $(, $rest)*]);
+		impl_vector!(@operators [], $ty, $scalar, [$first $(, $rest)*]);
+	};
+	($ty:ident<$t:ident>, [$($scalar:ty),+], $fields:tt) => {
+		impl_vector!(@fixed $ty, $t, $fields);
+		$(impl_vector!(@arithmetic fixed, $ty<$scalar>, $scalar, $fields);)+
+	};
+	(@fixed $ty:ident, $t:ident, [$first:ident $(, $rest:ident)*]) => {
+		impl<$t: $crate::FixedPoint> $ty<$t> {
+			pub const ZERO: Self = $ty::splat($t::ZERO);
+			pub const ONE: Self = $ty::splat($t::ONE);
+
+			pub const fn splat(value: $t) -> Self {
+				$ty::new(value $(, impl_vector!(@same $rest value))*)
+			}
+
+			/// Accumulates the squares at double width, so this does not overflow where `dot` would.
+			/// Saturates at the scalar's `MAX`.
+			pub fn magnitude(&self) -> $t {
+				$t::hypot(&[self.$first $(, self.$rest)*])
+			}
+
+			/// Panics if `self` is zero.
+			pub fn normalize(&mut self) {
+				let mut components = [self.$first $(, self.$rest)*];
+				$t::normalize(&mut components);
+				[self.$first $(, self.$rest)*] = components;
+			}
+
+			/// Returns a normalized copy of `self`, panicking if it is zero.
+			pub fn normalized(&self) -> Self {
+				let mut normalized = *self;
+				normalized.normalize();
+				normalized
+			}
+
+			/// Returns the component-wise minimum of `self` and `other`.
+			pub fn min(&self, other: Self) -> Self {
+				$ty::new(self.$first.min(other.$first) $(, self.$rest.min(other.$rest))*)
+			}
+
+			/// Returns the component-wise maximum of `self` and `other`.
+			pub fn max(&self, other: Self) -> Self {
+				$ty::new(self.$first.max(other.$first) $(, self.$rest.max(other.$rest))*)
+			}
+		}
+
+		impl_vector!(@operators [$t: $crate::FixedPoint], $ty<$t>, $t, [$first $(, $rest)*]);
+	};
+	(@same $field:ident $value:ident) => {
+		$value
+	};
+	(@add native, $a:expr, $b:expr) => { $a + $b };
+	(@add fixed, $a:expr, $b:expr) => { $a.add($b) };
+	(@sub native, $a:expr, $b:expr) => { $a - $b };
+	(@sub fixed, $a:expr, $b:expr) => { $a.sub($b) };
+	(@mul native, $a:expr, $b:expr) => { $a * $b };
+	(@mul fixed, $a:expr, $b:expr) => { $a.mul($b) };
+	(@arithmetic $kind:ident, $ty:ty, $scalar:ty, [$first:ident $(, $rest:ident)*]) => {
+		impl $ty {
+			/// Same as the `+` operator, usable in constant expressions.
+			#[allow(clippy::should_implement_trait)]
+			pub const fn add(self, rhs: $ty) -> $ty {
+				<$ty>::new(impl_vector!(@add $kind, self.$first, rhs.$first) $(, impl_vector!(@add $kind, self.$rest, rhs.$rest))*)
+			}
+
+			/// Same as the `-` operator, usable in constant expressions.
+			#[allow(clippy::should_implement_trait)]
+			pub const fn sub(self, rhs: $ty) -> $ty {
+				<$ty>::new(impl_vector!(@sub $kind, self.$first, rhs.$first) $(, impl_vector!(@sub $kind, self.$rest, rhs.$rest))*)
+			}
+
+			/// Same as multiplying by a scalar, usable in constant expressions.
+			pub const fn scale(self, scale: $scalar) -> $ty {
+				<$ty>::new(impl_vector!(@mul $kind, self.$first, scale) $(, impl_vector!(@mul $kind, self.$rest, scale))*)
+			}
+
+			pub const fn dot(self, rhs: $ty) -> $scalar {
+				let dot = impl_vector!(@mul $kind, self.$first, rhs.$first);
+				$(let dot = impl_vector!(@add $kind, dot, impl_vector!(@mul $kind, self.$rest, rhs.$rest));)*
+				dot
+			}
+		}
+	};
+	(@operators [$($generics:tt)*], $ty:ty, $scalar:ty, [$first:ident $(, $rest:ident)*]) => {
+		impl<$($generics)*> core::ops::Neg for $ty {
+			type Output = $ty;
+
+			fn neg(self) -> Self::Output {
+				<$ty>::new(-self.$first $(, -self.$rest)*)
+			}
+		}
+
+		impl_vector!(@op [$($generics)*], $ty, [$first $(, $rest)*], Add, add, AddAssign, add_assign, +, +=);
+		impl_vector!(@op [$($generics)*], $ty, [$first $(, $rest)*], Sub, sub, SubAssign, sub_assign, -, -=);
+		impl_vector!(@op [$($generics)*], $ty, [$first $(, $rest)*], Mul, mul, MulAssign, mul_assign, *, *=);
+		impl_vector!(@op [$($generics)*], $ty, [$first $(, $rest)*], Div, div, DivAssign, div_assign, /, /=);
+		impl_vector!(@scalar [$($generics)*], $ty, $scalar, [$first $(, $rest)*], Mul, mul, MulAssign, mul_assign, *, *=);
+		impl_vector!(@scalar [$($generics)*], $ty, $scalar, [$first $(, $rest)*], Div, div, DivAssign, div_assign, /, /=);
+	};
+	(@op [$($generics:tt)*], $ty:ty, [$($field:ident),+], $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $symbol:tt, $assign:tt) => {
+		impl<$($generics)*> core::ops::$op<$ty> for $ty {
+			type Output = $ty;
+
+			fn $fn(self, rhs: $ty) -> Self::Output {
+				<$ty>::new($(self.$field $symbol rhs.$field),+)
+			}
+		}
+		impl<$($generics)*> core::ops::$op_assign<$ty> for $ty {
+			fn $fn_assign(&mut self, rhs: $ty) {
+				$(self.$field $assign rhs.$field;)+
+			}
+		}
+	};
+	(@scalar [$($generics:tt)*], $ty:ty, $scalar:ty, [$($field:ident),+], $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $symbol:tt, $assign:tt) => {
+		impl<$($generics)*> core::ops::$op<$scalar> for $ty {
+			type Output = $ty;
+
+			fn $fn(self, rhs: $scalar) -> Self::Output {
+				<$ty>::new($(self.$field $symbol rhs),+)
+			}
+		}
+		impl<$($generics)*> core::ops::$op_assign<$scalar> for $ty {
+			fn $fn_assign(&mut self, rhs: $scalar) {
+				$(self.$field $assign rhs;)+
+			}
+		}
+	};
+}
+
+/// Magnitude, normalization and the component-wise math of an `f32` vector.
+macro_rules! impl_float_vector {
+	($ty:ident, [$first:ident $(, $rest:ident)*]) => {
+		impl $ty {
+			pub fn magnitude(&self) -> f32 {
+				::libm::sqrtf(self.dot(*self))
+			}
+
+			pub fn normalize(&mut self) {
+				let magnitude = self.magnitude();
+				self.$first /= magnitude;
+				$(self.$rest /= magnitude;)*
+			}
+
+			/// Returns a normalized copy of `self`.
+			pub fn normalized(&self) -> $ty {
+				let mut normalized = *self;
+				normalized.normalize();
+				normalized
+			}
+
+			/// Returns the component-wise minimum of `self` and `other`.
+			pub fn min(&self, other: $ty) -> $ty {
+				$ty::new(::libm::fminf(self.$first, other.$first) $(, ::libm::fminf(self.$rest, other.$rest))*)
+			}
+
+			/// Returns the component-wise maximum of `self` and `other`.
+			pub fn max(&self, other: $ty) -> $ty {
+				$ty::new(::libm::fmaxf(self.$first, other.$first) $(, ::libm::fmaxf(self.$rest, other.$rest))*)
+			}
+
+			/// Clamps each component between the matching components of `min` and `max`.
+			pub fn clamp(&self, min: $ty, max: $ty) -> $ty {
+				self.max(min).min(max)
+			}
+
+			/// Returns the absolute value of each component.
+			pub fn abs(&self) -> $ty {
+				self.map(::libm::fabsf)
+			}
+
+			/// Returns `1.0` or `-1.0` per component following its sign, or NaN for NaN components.
+			pub fn signum(&self) -> $ty {
+				self.map($crate::scalar::signumf)
+			}
+
+			/// Rounds each component down to the nearest integer.
+			pub fn floor(&self) -> $ty {
+				self.map(::libm::floorf)
+			}
+
+			/// Rounds each component up to the nearest integer.
+			pub fn ceil(&self) -> $ty {
+				self.map(::libm::ceilf)
+			}
+
+			/// Rounds each component to the nearest integer, rounding half-way cases away from zero.
+			pub fn round(&self) -> $ty {
+				self.map(::libm::roundf)
+			}
+
+			/// Rounds each component toward zero.
+			pub fn trunc(&self) -> $ty {
+				self.map(::libm::truncf)
+			}
+
+			/// Returns the fractional part of each component, `self - self.floor()`, as GLSL `fract`
+			/// and HLSL `frac` do. Negative components wrap up into `[0, 1)`.
+			pub fn fract(&self) -> $ty {
+				self.map(|value| value - ::libm::floorf(value))
+			}
+
+			/// Raises each component to the power of `n`.
+			pub fn powf(&self, n: f32) -> $ty {
+				self.map(|value| ::libm::powf(value, n))
+			}
+
+			/// Returns `e^x` for each component.
+			pub fn exp(&self) -> $ty {
+				self.map(::libm::expf)
+			}
+
+			/// Returns the natural logarithm of each component.
+			pub fn ln(&self) -> $ty {
+				self.map(::libm::logf)
+			}
+
+			/// Returns the square root of each component.
+			pub fn sqrt(&self) -> $ty {
+				self.map(::libm::sqrtf)
+			}
+
+			/// Returns `1.0 / x` for each component.
+			pub fn recip(&self) -> $ty {
+				self.map(|value| 1.0 / value)
+			}
+
+			/// Computes `self * a + b` per component with a single rounding error.
+			pub fn mul_add(&self, a: $ty, b: $ty) -> $ty {
+				$ty::new(::libm::fmaf(self.$first, a.$first, b.$first) $(, ::libm::fmaf(self.$rest, a.$rest, b.$rest))*)
+			}
+
+			/// Returns the smallest component.
+			pub fn min_element(&self) -> f32 {
+				let min = self.$first;
+				$(let min = ::libm::fminf(min, self.$rest);)*
+				min
+			}
+
+			/// Returns the largest component.
+			pub fn max_element(&self) -> f32 {
+				let max = self.$first;
+				$(let max = ::libm::fmaxf(max, self.$rest);)*
+				max
+			}
+
+			/// Returns `self` with the magnitude of each component and the sign of the matching component of `sign`.
+			pub fn copysign(&self, sign: $ty) -> $ty {
+				$ty::new(::libm::copysignf(self.$first, sign.$first) $(, ::libm::copysignf(self.$rest, sign.$rest))*)
+			}
+
+			#[inline(always)]
+			fn map(&self, f: impl Fn(f32) -> f32) -> $ty {
+				$ty::new(f(self.$first) $(, f(self.$rest))*)
+			}
+		}
+	};
+}
+
+pub(crate) use impl_float_vector;
+pub(crate) use impl_vector;
+
+#[cfg(test)]
+mod tests {
+	use crate::{Fixed32, Fixed64, FixedVector2, FixedVector3, IVector2, Vector2, Vector3, Vector4};
+
+	#[test]
+	fn every_dimension() {
+		const HALF: Vector4 = Vector4::splat(1.0).scale(0.5).add(Vector4::ZERO);
+		assert_eq!(HALF.dot(Vector4::ONE), 2.0);
+		assert_eq!(-Vector4::ONE + Vector4::ONE * 2.0, Vector4::ONE);
+		assert_eq!(Vector4::new(0.0, 3.0, 0.0, 4.0).normalized(), Vector4::new(0.0, 0.6, 0.0, 0.8));
+
+		assert_eq!(Vector3::new(3.0, 0.0, 4.0).normalized(), Vector3::new(0.6, 0.0, 0.8));
+		assert_eq!(-Vector3::splat(2.0) / 2.0, -Vector3::ONE);
+		assert_eq!(Vector2::new(1.0, -3.0).max_element(), 1.0);
+
+		const STEP: IVector2 = IVector2::UP.add(IVector2::splat(2)).scale(3);
+		assert_eq!(STEP, IVector2::new(6, 9));
+		assert_eq!(-STEP - IVector2::ONE, IVector2::new(-7, -10));
+		assert_eq!(STEP.dot(IVector2::ONE), 15);
+
+		const HALF_STEP: FixedVector3 = FixedVector3::splat(Fixed32::ONE).scale(Fixed32::HALF).sub(FixedVector3::ZERO);
+		assert_eq!(HALF_STEP.dot(FixedVector3::ONE), Fixed32::from_f32(1.5));
+		assert_eq!(-HALF_STEP + HALF_STEP * Fixed32::from_int(2), HALF_STEP);
+		const WIDE: FixedVector2<Fixed64> = FixedVector2::new(Fixed64::from_int(2), Fixed64::from_int(3)).add(FixedVector2::ONE);
+		assert_eq!(Vector2::from(WIDE.normalized()), Vector2::new(0.6, 0.8));
+		assert_eq!(WIDE.min(FixedVector2::splat(Fixed64::from_int(4))).max(FixedVector2::ZERO), FixedVector2::new(Fixed64::from_int(3), Fixed64::from_int(4)));
+	}
+}
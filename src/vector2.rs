@@ -1,156 +1,64 @@
+use crate::vector::{impl_float_vector, impl_vector};
 use crate::IVector2;
-use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
-use libm::sqrtf;
+use libm::{ceilf, floorf, roundf};
 
 /// A single precision 2D Vector.
 /// This struct is [repr(C)] with an alignment of 8 to satisfy std140
 #[repr(C)]
 #[repr(align(8))]
 #[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Vector2 {
     pub x: f32,
     pub y: f32,
 }
 
 impl Vector2 {
-    pub const ZERO: Vector2 = Vector2 { x: 0.0, y: 0.0 };
-    pub const ONE: Vector2 = Vector2 { x: 1.0, y: 1.0 };
     pub const UP: Vector2 = Vector2 { x: 0.0, y: 1.0 };
     pub const DOWN: Vector2 = Vector2 { x: 0.0, y: -1.0 };
     pub const LEFT: Vector2 = Vector2 { x: -1.0, y: 0.0 };
     pub const RIGHT: Vector2 = Vector2 { x: 1.0, y: 0.0 };
 
-    pub fn magnitude(&self) -> f32 {
-        sqrtf((self.x * self.x) + (self.y * self.y))
+    pub const fn new(x: f32, y: f32) -> Self {
+        Vector2 { x, y }
     }
 
-    pub fn normalize(&mut self) {
-        let magnitude = self.magnitude();
-        self.x /= magnitude;
-        self.y /= magnitude;
-    }
-
-    pub fn normalized(&self) -> Vector2 {
-        Vector2 {
-            x: self.x / self.magnitude(),
-            y: self.y / self.magnitude(),
+    /// Converts to the [`IVector2`] containing this point, rounding toward negative infinity.
+    pub fn floor_to_ivec(&self) -> IVector2 {
+        IVector2 {
+            x: floorf(self.x) as i32,
+            y: floorf(self.y) as i32,
         }
     }
-}
-
-impl From<IVector2> for Vector2 {
-    fn from(value: IVector2) -> Self {
-        Vector2 {
-            x: value.x as f32,
-            y: value.y as f32,
-        }
-    }
-}
-
-// F32 OPS
-impl Mul<f32> for Vector2 {
-    type Output = Vector2;
-
-    fn mul(self, rhs: f32) -> Self::Output {
-        Vector2 {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        }
-    }
-}
-impl MulAssign<f32> for Vector2 {
-    fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-    }
-}
-
-impl Div<f32> for Vector2 {
-    type Output = Vector2;
-
-    fn div(self, rhs: f32) -> Self::Output {
-        Vector2 {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        }
-    }
-}
-impl DivAssign<f32> for Vector2 {
-    fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-    }
-}
 
-// VECTOR2 OPS
-impl Add<Vector2> for Vector2 {
-    type Output = Vector2;
-
-    fn add(self, rhs: Vector2) -> Self::Output {
-        Vector2 {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
+    /// Converts to the nearest [`IVector2`], rounding half-way cases away from zero.
+    pub fn round_to_ivec(&self) -> IVector2 {
+        IVector2 {
+            x: roundf(self.x) as i32,
+            y: roundf(self.y) as i32,
         }
     }
-}
-impl AddAssign<Vector2> for Vector2 {
-    fn add_assign(&mut self, rhs: Vector2) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-
-impl Sub<Vector2> for Vector2 {
-    type Output = Vector2;
 
-    fn sub(self, rhs: Vector2) -> Self::Output {
-        Vector2 {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
+    /// Converts to an [`IVector2`], rounding toward positive infinity.
+    pub fn ceil_to_ivec(&self) -> IVector2 {
+        IVector2 {
+            x: ceilf(self.x) as i32,
+            y: ceilf(self.y) as i32,
         }
     }
 }
-impl SubAssign<Vector2> for Vector2 {
-    fn sub_assign(&mut self, rhs: Vector2) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-    }
-}
-
-impl Mul<Vector2> for Vector2 {
-    type Output = Vector2;
 
-    fn mul(self, rhs: Vector2) -> Self::Output {
+impl From<IVector2> for Vector2 {
+    fn from(value: IVector2) -> Self {
         Vector2 {
-            x: self.x * rhs.x,
-            y: self.y * rhs.y,
+            x: value.x as f32,
+            y: value.y as f32,
         }
     }
 }
-impl MulAssign<Vector2> for Vector2 {
-    fn mul_assign(&mut self, rhs: Vector2) {
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-    }
-}
-
-impl Div<Vector2> for Vector2 {
-    type Output = Vector2;
 
-    fn div(self, rhs: Vector2) -> Self::Output {
-        Vector2 {
-            x: self.x / rhs.x,
-            y: self.y / rhs.y,
-        }
-    }
-}
-impl DivAssign<Vector2> for Vector2 {
-    fn div_assign(&mut self, rhs: Vector2) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-    }
-}
+impl_vector!(Vector2, f32, 0.0, 1.0, [x, y]);
+impl_float_vector!(Vector2, [x, y]);
 
 #[cfg(test)]
 mod tests {
@@ -162,4 +70,32 @@ mod tests {
         assert_eq!(Vector2 { x: 5.0, y: 1.0 } * Vector2 { x: 5.0, y: 6.9 }, Vector2 { x: 5.0 * 5.0, y: 1.0 * 6.9 });
         assert_eq!(Vector2 { x: 8.0, y: 5.6 } / Vector2 { x: 2.0, y: 1.0 }, Vector2 { x: 8.0 / 2.0, y: 5.6 / 1.0 });
     }
-}
\ No newline at end of file
+    #[test]
+    fn component_wise() {
+        let v = Vector2 { x: -1.5, y: 2.25 };
+        assert_eq!(v.abs(), Vector2 { x: 1.5, y: 2.25 });
+        assert_eq!(v.floor(), Vector2 { x: -2.0, y: 2.0 });
+        assert_eq!(v.ceil(), Vector2 { x: -1.0, y: 3.0 });
+        assert_eq!(v.round(), Vector2 { x: -2.0, y: 2.0 });
+        assert_eq!(v.trunc(), Vector2 { x: -1.0, y: 2.0 });
+        assert_eq!(v.fract(), Vector2 { x: 0.5, y: 0.25 });
+        assert_eq!(Vector2 { x: -0.25, y: -3.0 }.fract(), Vector2 { x: 0.75, y: 0.0 });
+        assert_eq!(v.signum(), Vector2 { x: -1.0, y: 1.0 });
+        assert_eq!(v.min_element(), -1.5);
+        assert_eq!(v.max_element(), 2.25);
+        assert_eq!(v.clamp(Vector2::ONE * -1.0, Vector2::ONE), Vector2 { x: -1.0, y: 1.0 });
+        assert_eq!(Vector2::ONE.copysign(v), Vector2 { x: -1.0, y: 1.0 });
+        assert_eq!(Vector2 { x: 4.0, y: 16.0 }.sqrt(), Vector2 { x: 2.0, y: 4.0 });
+        assert_eq!(v.mul_add(Vector2::ONE * 2.0, Vector2::ONE), Vector2 { x: -2.0, y: 5.5 });
+    }
+
+    #[test]
+    fn to_ivec() {
+        use crate::IVector2;
+        let v = Vector2 { x: -0.5, y: 1.5 };
+        assert_eq!(v.floor_to_ivec(), IVector2 { x: -1, y: 1 });
+        assert_eq!(v.round_to_ivec(), IVector2 { x: -1, y: 2 });
+        assert_eq!(v.ceil_to_ivec(), IVector2 { x: 0, y: 2 });
+        assert_eq!(IVector2::from(v), IVector2 { x: 0, y: 1 });
+    }
+}
@@ -1,158 +1,97 @@
-use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
-use libm::sqrtf;
+use crate::batch;
+use crate::vector::{impl_float_vector, impl_vector};
+use core::fmt;
 
+/// A single precision 3D Vector.
+/// This struct is [repr(C)] with an alignment of 16 to satisfy std140, the tail padding is an
+/// explicit field so the type has no uninitialized bytes. Build values with [`Vector3::new`].
 #[repr(C)]
 #[repr(align(16))]
-#[derive(Copy, Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Vector3 {
     pub x: f32,
     pub y: f32,
     pub z: f32,
+    #[doc(hidden)]
+    pub _padding: f32,
 }
 
 impl Vector3 {
-    pub const ZERO: Vector3 = Vector3 { x: 0.0, y: 0.0, z: 0.0 };
-    pub const ONE: Vector3 = Vector3 { x: 1.0, y: 1.0, z: 1.0 };
-    pub const UP: Vector3 = Vector3 { x: 0.0, y: 1.0, z: 0.0 };
-    pub const DOWN: Vector3 = Vector3 { x: 0.0, y: -1.0, z: 0.0 };
-    pub const LEFT: Vector3 = Vector3 { x: -1.0, y: 0.0, z: 0.0 };
-    pub const RIGHT: Vector3 = Vector3 { x: 1.0, y: 0.0, z: 0.0 };
-    pub const FORWARD: Vector3 = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
-    pub const BACK: Vector3 = Vector3 { x: 0.0, y: 0.0, z: -1.0 };
-
-    pub fn magnitude(&self) -> f32 {
-        sqrtf((self.x * self.x) + (self.y * self.y) + (self.z * self.z))
+    pub const UP: Vector3 = Vector3::new(0.0, 1.0, 0.0);
+    pub const DOWN: Vector3 = Vector3::new(0.0, -1.0, 0.0);
+    pub const LEFT: Vector3 = Vector3::new(-1.0, 0.0, 0.0);
+    pub const RIGHT: Vector3 = Vector3::new(1.0, 0.0, 0.0);
+    pub const FORWARD: Vector3 = Vector3::new(0.0, 0.0, 1.0);
+    pub const BACK: Vector3 = Vector3::new(0.0, 0.0, -1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Vector3 { x, y, z, _padding: 0.0 }
     }
 
-    pub fn normalize(&mut self) {
-        let magnitude = self.magnitude();
-        self.x /= magnitude;
-        self.y /= magnitude;
-        self.z /= magnitude;
+    pub const fn cross(self, rhs: Vector3) -> Vector3 {
+        Vector3::new(
+            (self.y * rhs.z) - (self.z * rhs.y),
+            (self.z * rhs.x) - (self.x * rhs.z),
+            (self.x * rhs.y) - (self.y * rhs.x),
+        )
     }
-}
-
-// F32 OPS
-impl Mul<f32> for Vector3 {
-    type Output = Vector3;
 
-    fn mul(self, rhs: f32) -> Self::Output {
-        Vector3 {
-            x: self.x * rhs,
-            y: self.y * rhs,
-            z: self.z * rhs,
-        }
-    }
-}
-impl MulAssign<f32> for Vector3 {
-    fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
+    /// [`Vector3::normalize`] over a slice.
+    pub fn normalize_slice(vectors: &mut [Vector3]) {
+        batch::normalize_in_place(vectors);
     }
 }
 
-impl Div<f32> for Vector3 {
-    type Output = Vector3;
+impl_vector!(Vector3, f32, 0.0, 1.0, [x, y, z]);
+impl_float_vector!(Vector3, [x, y, z]);
 
-    fn div(self, rhs: f32) -> Self::Output {
-        Vector3 {
-            x: self.x / rhs,
-            y: self.y / rhs,
-            z: self.z / rhs,
-        }
-    }
-}
-impl DivAssign<f32> for Vector3 {
-    fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
+// Padding is ignored, it may be non-zero after a cast from bytes.
+impl PartialEq for Vector3 {
+    fn eq(&self, other: &Vector3) -> bool {
+        (self.x == other.x) && (self.y == other.y) && (self.z == other.z)
     }
 }
 
-// VECTOR2 OPS
-impl Add<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn add(self, rhs: Vector3) -> Self::Output {
-        Vector3 {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-        }
-    }
-}
-impl AddAssign<Vector3> for Vector3 {
-    fn add_assign(&mut self, rhs: Vector3) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-    }
-}
-
-
-impl Sub<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn sub(self, rhs: Vector3) -> Self::Output {
-        Vector3 {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
-        }
-    }
-}
-impl SubAssign<Vector3> for Vector3 {
-    fn sub_assign(&mut self, rhs: Vector3) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
+impl fmt::Debug for Vector3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vector3").field("x", &self.x).field("y", &self.y).field("z", &self.z).finish()
     }
 }
 
-
-impl Mul<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn mul(self, rhs: Vector3) -> Self::Output {
-        Vector3 {
-            x: self.x * rhs.x,
-            y: self.y * rhs.y,
-            z: self.z * rhs.z,
-        }
-    }
-}
-impl MulAssign<Vector3> for Vector3 {
-    fn mul_assign(&mut self, rhs: Vector3) {
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-        self.z *= rhs.z;
-    }
+#[test]
+fn test() {
+    assert_eq!(Vector3::new(2.0, 2.0, 2.0) + Vector3::new(2.0, 2.0, 2.0), Vector3::new(2.0 + 2.0, 2.0 + 2.0, 2.0 + 2.0));
 }
-
-
-impl Div<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn div(self, rhs: Vector3) -> Self::Output {
-        Vector3 {
-            x: self.x / rhs.x,
-            y: self.y / rhs.y,
-            z: self.z / rhs.z,
-        }
-    }
+#[test]
+fn const_ops() {
+    const GRAVITY: Vector3 = Vector3::DOWN.scale(9.8);
+    const DIAGONAL: Vector3 = Vector3::RIGHT.add(Vector3::UP).sub(Vector3::splat(0.5));
+    const NORMAL: Vector3 = Vector3::RIGHT.cross(Vector3::UP);
+    assert_eq!(GRAVITY, Vector3::DOWN * 9.8);
+    assert_eq!(DIAGONAL, Vector3::new(0.5, 0.5, -0.5));
+    assert_eq!(NORMAL, Vector3::FORWARD);
+    assert_eq!(DIAGONAL.dot(NORMAL), -0.5);
 }
-impl DivAssign<Vector3> for Vector3 {
-    fn div_assign(&mut self, rhs: Vector3) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-        self.z /= rhs.z;
-    }
+#[test]
+fn component_wise() {
+    let a = Vector3::new(1.0, -4.0, 9.0);
+    let b = Vector3::new(2.0, -5.0, 3.0);
+    assert_eq!(a.min(b), Vector3::new(1.0, -5.0, 3.0));
+    assert_eq!(a.max(b), Vector3::new(2.0, -4.0, 9.0));
+    assert_eq!(a.abs().sqrt(), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(a.recip(), Vector3::new(1.0, -0.25, 1.0 / 9.0));
+    assert_eq!(Vector3::ZERO.exp(), Vector3::ONE);
+    assert_eq!(Vector3::ONE.ln(), Vector3::ZERO);
+    assert_eq!(b.powf(2.0), Vector3::new(4.0, 25.0, 9.0));
 }
 
+#[cfg(feature = "bytemuck")]
 #[test]
-fn test() {
-    assert_eq!(Vector3{ x: 2.0, y: 2.0, z: 2.0 } + Vector3{ x: 2.0, y: 2.0, z: 2.0 }, Vector3{ x: 2.0 + 2.0, y: 2.0 + 2.0, z: 2.0 + 2.0 });
-}
\ No newline at end of file
+fn cast_bytes() {
+    let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::ONE];
+    let bytes: &[u8] = bytemuck::cast_slice(&vectors);
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(&bytes[12..16], &[0; 4]);
+    assert_eq!(bytemuck::cast_slice::<u8, Vector3>(bytes), &vectors);
+}
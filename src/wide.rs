@@ -0,0 +1,325 @@
+//! Structure-of-arrays vectors, four or eight [`Vector3`]s stored as lanes of x, y and z.
+//!
+//! Every operation is a plain loop over the lanes, which LLVM turns into one SIMD instruction
+//! per component. Load a chunk with [`Vector3x4::gather`], work on it, then write it back with
+//! [`Vector3x4::scatter`]:
+//!
+//! ```
+//! use maths::{Vector3, Vector3x4};
+//!
+//! let mut velocities = [Vector3::new(3.0, 0.0, 4.0); 6];
+//! for chunk in velocities.chunks_mut(4) {
+//!     let mut wide = Vector3x4::gather(chunk);
+//!     wide.normalize();
+//!     wide.scatter(chunk);
+//! }
+//! assert_eq!(velocities[5], Vector3::new(0.6, 0.0, 0.8));
+//! ```
+//!
+//! Lanes past the end of a short slice are gathered as zero, and normalize to NaN, but
+//! [`Vector3x4::scatter`] never writes them back.
+
+use crate::Vector3;
+use core::ops::{Add, AddAssign, BitAnd, BitOr, Div, DivAssign, Mul, MulAssign, Neg, Not, Sub, SubAssign};
+use libm::{fmaxf, fminf, sqrtf};
+
+macro_rules! impl_mask {
+	($mask:ident, $lanes:literal) => {
+		/// One bool per lane, produced by comparisons and consumed by `select`.
+		#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+		pub struct $mask(pub [bool; $lanes]);
+
+		impl $mask {
+			pub const fn splat(value: bool) -> Self {
+				$mask([value; $lanes])
+			}
+
+			/// Lanes where `a < b`.
+			pub fn lt(a: [f32; $lanes], b: [f32; $lanes]) -> Self {
+				$mask(core::array::from_fn(|i| a[i] < b[i]))
+			}
+
+			/// Lanes where `a <= b`.
+			pub fn le(a: [f32; $lanes], b: [f32; $lanes]) -> Self {
+				$mask(core::array::from_fn(|i| a[i] <= b[i]))
+			}
+
+			/// Lanes where `a > b`.
+			pub fn gt(a: [f32; $lanes], b: [f32; $lanes]) -> Self {
+				$mask(core::array::from_fn(|i| a[i] > b[i]))
+			}
+
+			/// Lanes where `a >= b`.
+			pub fn ge(a: [f32; $lanes], b: [f32; $lanes]) -> Self {
+				$mask(core::array::from_fn(|i| a[i] >= b[i]))
+			}
+
+			pub fn all(&self) -> bool {
+				self.0.iter().all(|lane| *lane)
+			}
+
+			pub fn any(&self) -> bool {
+				self.0.iter().any(|lane| *lane)
+			}
+
+			/// Lane `i` is bit `i`.
+			pub fn bitmask(&self) -> u32 {
+				self.0.iter().enumerate().fold(0, |bits, (i, lane)| bits | ((*lane as u32) << i))
+			}
+		}
+
+		impl BitAnd for $mask {
+			type Output = $mask;
+
+			fn bitand(self, rhs: $mask) -> Self::Output {
+				$mask(core::array::from_fn(|i| self.0[i] & rhs.0[i]))
+			}
+		}
+
+		impl BitOr for $mask {
+			type Output = $mask;
+
+			fn bitor(self, rhs: $mask) -> Self::Output {
+				$mask(core::array::from_fn(|i| self.0[i] | rhs.0[i]))
+			}
+		}
+
+		impl Not for $mask {
+			type Output = $mask;
+
+			fn not(self) -> Self::Output {
+				$mask(self.0.map(|lane| !lane))
+			}
+		}
+	};
+}
+
+macro_rules! impl_wide {
+	($ty:ident, $mask:ident, $lanes:literal) => {
+		#[doc = concat!(stringify!($lanes), " [`Vector3`]s stored as lanes of x, y and z.")]
+		#[repr(C)]
+		#[derive(Clone, Copy, Debug, PartialEq)]
+		#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+		pub struct $ty {
+			pub x: [f32; $lanes],
+			pub y: [f32; $lanes],
+			pub z: [f32; $lanes],
+		}
+
+		impl $ty {
+			pub const LANES: usize = $lanes;
+			pub const ZERO: $ty = $ty::splat(Vector3::ZERO);
+
+			pub const fn new(x: [f32; $lanes], y: [f32; $lanes], z: [f32; $lanes]) -> Self {
+				$ty { x, y, z }
+			}
+
+			/// Every lane set to `v`.
+			pub const fn splat(v: Vector3) -> Self {
+				$ty { x: [v.x; $lanes], y: [v.y; $lanes], z: [v.z; $lanes] }
+			}
+
+			/// Loads the first lanes from `values`, any lanes past its end are zero.
+			pub fn gather(values: &[Vector3]) -> Self {
+				let mut wide = $ty::ZERO;
+				for (i, v) in values.iter().take($lanes).enumerate() {
+					wide.x[i] = v.x;
+					wide.y[i] = v.y;
+					wide.z[i] = v.z;
+				}
+				wide
+			}
+
+			/// Stores the lanes into `out`, stopping early when it is shorter than the lane count.
+			pub fn scatter(&self, out: &mut [Vector3]) {
+				for (i, v) in out.iter_mut().take($lanes).enumerate() {
+					*v = self.lane(i);
+				}
+			}
+
+			/// Panics if `i` is not less than the lane count.
+			pub const fn lane(&self, i: usize) -> Vector3 {
+				Vector3::new(self.x[i], self.y[i], self.z[i])
+			}
+
+			/// Panics if `i` is not less than the lane count.
+			pub fn set_lane(&mut self, i: usize, v: Vector3) {
+				self.x[i] = v.x;
+				self.y[i] = v.y;
+				self.z[i] = v.z;
+			}
+
+			pub fn dot(&self, rhs: $ty) -> [f32; $lanes] {
+				core::array::from_fn(|i| (self.x[i] * rhs.x[i]) + (self.y[i] * rhs.y[i]) + (self.z[i] * rhs.z[i]))
+			}
+
+			pub fn cross(&self, rhs: $ty) -> $ty {
+				$ty {
+					x: core::array::from_fn(|i| (self.y[i] * rhs.z[i]) - (self.z[i] * rhs.y[i])),
+					y: core::array::from_fn(|i| (self.z[i] * rhs.x[i]) - (self.x[i] * rhs.z[i])),
+					z: core::array::from_fn(|i| (self.x[i] * rhs.y[i]) - (self.y[i] * rhs.x[i])),
+				}
+			}
+
+			pub fn magnitude(&self) -> [f32; $lanes] {
+				self.dot(*self).map(sqrtf)
+			}
+
+			/// Normalizes every lane, zero lanes become NaN as with [`Vector3::normalize`].
+			pub fn normalize(&mut self) {
+				let inverse = self.magnitude().map(|magnitude| 1.0 / magnitude);
+				*self *= inverse;
+			}
+
+			/// Returns the component-wise minimum of `self` and `other`.
+			pub fn min(&self, other: $ty) -> $ty {
+				$ty {
+					x: core::array::from_fn(|i| fminf(self.x[i], other.x[i])),
+					y: core::array::from_fn(|i| fminf(self.y[i], other.y[i])),
+					z: core::array::from_fn(|i| fminf(self.z[i], other.z[i])),
+				}
+			}
+
+			/// Returns the component-wise maximum of `self` and `other`.
+			pub fn max(&self, other: $ty) -> $ty {
+				$ty {
+					x: core::array::from_fn(|i| fmaxf(self.x[i], other.x[i])),
+					y: core::array::from_fn(|i| fmaxf(self.y[i], other.y[i])),
+					z: core::array::from_fn(|i| fmaxf(self.z[i], other.z[i])),
+				}
+			}
+
+			/// Lanes where all three components are equal.
+			pub fn cmpeq(&self, other: $ty) -> $mask {
+				$mask(core::array::from_fn(|i| self.x[i] == other.x[i] && self.y[i] == other.y[i] && self.z[i] == other.z[i]))
+			}
+
+			/// Lane-wise `if mask { if_true } else { if_false }`.
+			pub fn select(mask: $mask, if_true: $ty, if_false: $ty) -> $ty {
+				let pick = |i: usize, a: f32, b: f32| if mask.0[i] { a } else { b };
+				$ty {
+					x: core::array::from_fn(|i| pick(i, if_true.x[i], if_false.x[i])),
+					y: core::array::from_fn(|i| pick(i, if_true.y[i], if_false.y[i])),
+					z: core::array::from_fn(|i| pick(i, if_true.z[i], if_false.z[i])),
+				}
+			}
+		}
+
+		impl From<[Vector3; $lanes]> for $ty {
+			fn from(values: [Vector3; $lanes]) -> Self {
+				$ty::gather(&values)
+			}
+		}
+
+		impl From<$ty> for [Vector3; $lanes] {
+			fn from(wide: $ty) -> Self {
+				core::array::from_fn(|i| wide.lane(i))
+			}
+		}
+
+		impl Neg for $ty {
+			type Output = $ty;
+
+			fn neg(self) -> Self::Output {
+				$ty { x: self.x.map(|x| -x), y: self.y.map(|y| -y), z: self.z.map(|z| -z) }
+			}
+		}
+
+		impl_wide_op!($ty, $ty, Add, add, AddAssign, add_assign, +=, |rhs: $ty, i| (rhs.x[i], rhs.y[i], rhs.z[i]));
+		impl_wide_op!($ty, $ty, Sub, sub, SubAssign, sub_assign, -=, |rhs: $ty, i| (rhs.x[i], rhs.y[i], rhs.z[i]));
+		impl_wide_op!($ty, $ty, Mul, mul, MulAssign, mul_assign, *=, |rhs: $ty, i| (rhs.x[i], rhs.y[i], rhs.z[i]));
+		impl_wide_op!($ty, $ty, Div, div, DivAssign, div_assign, /=, |rhs: $ty, i| (rhs.x[i], rhs.y[i], rhs.z[i]));
+		// Per lane scalars, such as the result of dot
+		impl_wide_op!($ty, [f32; $lanes], Mul, mul, MulAssign, mul_assign, *=, |rhs: [f32; $lanes], i| (rhs[i], rhs[i], rhs[i]));
+		impl_wide_op!($ty, [f32; $lanes], Div, div, DivAssign, div_assign, /=, |rhs: [f32; $lanes], i| (rhs[i], rhs[i], rhs[i]));
+		impl_wide_op!($ty, f32, Mul, mul, MulAssign, mul_assign, *=, |rhs: f32, _i| (rhs, rhs, rhs));
+		impl_wide_op!($ty, f32, Div, div, DivAssign, div_assign, /=, |rhs: f32, _i| (rhs, rhs, rhs));
+	};
+}
+
+/// Implements a binary operator and its assigning form, `$lane` gives the right hand side
+/// components for lane `i`.
+macro_rules! impl_wide_op {
+	($ty:ident, $rhs:ty, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $assign:tt, $lane:expr) => {
+		impl $op<$rhs> for $ty {
+			type Output = $ty;
+
+			fn $fn(mut self, rhs: $rhs) -> Self::Output {
+				self $assign rhs;
+				self
+			}
+		}
+
+		impl $op_assign<$rhs> for $ty {
+			#[allow(clippy::redundant_closure_call)]
+			fn $fn_assign(&mut self, rhs: $rhs) {
+				for i in 0..Self::LANES {
+					let (x, y, z) = ($lane)(rhs, i);
+					self.x[i] $assign x;
+					self.y[i] $assign y;
+					self.z[i] $assign z;
+				}
+			}
+		}
+	};
+}
+
+impl_mask!(Mask4, 4);
+impl_mask!(Mask8, 8);
+impl_wide!(Vector3x4, Mask4, 4);
+impl_wide!(Vector3x8, Mask8, 8);
+
+#[cfg(test)]
+mod tests {
+	use crate::{Mask4, Vector3, Vector3x4, Vector3x8};
+
+	fn vectors() -> [Vector3; 8] {
+		core::array::from_fn(|i| {
+			let i = i as f32;
+			Vector3::new(i + 1.0, 2.0 - i, i * i * 0.5)
+		})
+	}
+
+	#[test]
+	fn matches_scalar() {
+		let a = vectors();
+		let b = a.map(|v| v.cross(Vector3::UP) + Vector3::ONE);
+		let (wide_a, wide_b) = (Vector3x8::from(a), Vector3x8::from(b));
+
+		let sum: [Vector3; 8] = (wide_a + wide_b * 2.0 - wide_a / wide_b).into();
+		let cross: [Vector3; 8] = wide_a.cross(wide_b).into();
+		let dot = wide_a.dot(wide_b);
+		let mut normalized = wide_a;
+		normalized.normalize();
+		for i in 0..8 {
+			assert_eq!(sum[i], a[i] + b[i] * 2.0 - a[i] / b[i]);
+			assert_eq!(cross[i], a[i].cross(b[i]));
+			assert_eq!(dot[i], a[i].dot(b[i]));
+			assert!((normalized.lane(i) - (a[i] / a[i].magnitude())).magnitude() < 1e-6);
+		}
+		assert_eq!(-(-wide_a), wide_a);
+		assert_eq!(wide_a.min(wide_b).max(wide_a), wide_a);
+	}
+
+	#[test]
+	fn gather_and_select() {
+		let values = vectors();
+		let wide = Vector3x4::gather(&values[..3]);
+		assert_eq!(wide.lane(2), values[2]);
+		assert_eq!(wide.lane(3), Vector3::ZERO);
+
+		let mut out = [Vector3::ONE; 2];
+		wide.scatter(&mut out);
+		assert_eq!(out, [values[0], values[1]]);
+
+		// Clamp the speed of every lane to 2
+		let speed = wide.magnitude();
+		let fast = Mask4::gt(speed, [2.0; 4]);
+		assert_eq!(fast, Mask4([true, true, true, false]));
+		assert_eq!(fast.bitmask(), 0b0111);
+		assert!(fast.any() && !fast.all() && (!fast).any());
+		let clamped = Vector3x4::select(fast, wide * speed.map(|s| 2.0 / s), wide);
+		assert!(Mask4::le(clamped.magnitude(), [2.0 + 1e-6; 4]).all());
+		assert_eq!(clamped.cmpeq(wide), !fast);
+	}
+}